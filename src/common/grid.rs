@@ -1,6 +1,14 @@
 //! A general-purpose 2D grid with constant width and height.
 
-use std::{borrow::Cow, cmp::Ordering};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::{HashSet, VecDeque},
+    fmt,
+    str::FromStr,
+};
+
+use super::{Dir, CARDINAL};
 
 /// A general-purpose 2D grid with constant width and height.
 pub struct Grid<'a, T>
@@ -12,6 +20,37 @@ where
     data: Vec<Cow<'a, [T]>>,
 }
 
+/// How positions outside of a [`Grid`] are treated; see [`Grid::get_with`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Addressing {
+    /// There are no cells outside; this is how [`Grid::get`] works.
+    #[default]
+    Bounded,
+    /// The grid wraps around at its edges, like a torus; which is the same as it being repeated
+    /// infinitely in all directions. See also [`Grid::get_tiled`].
+    Wrap,
+    /// Positions outside are moved to the closest cell on the edge.
+    Clamp,
+}
+
+impl Addressing {
+    /// Maps `p` to the position of the cell it refers to in a grid of the given size, if any.
+    fn resolve(self, p: (i32, i32), width: usize, height: usize) -> Option<(i32, i32)> {
+        let (width, height) = (width as i32, height as i32);
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        match self {
+            Addressing::Bounded => {
+                ((0..width).contains(&p.0) && (0..height).contains(&p.1)).then_some(p)
+            }
+            Addressing::Wrap => Some((p.0.rem_euclid(width), p.1.rem_euclid(height))),
+            Addressing::Clamp => Some((p.0.clamp(0, width - 1), p.1.clamp(0, height - 1))),
+        }
+    }
+}
+
 impl<T> std::ops::Index<(i32, i32)> for Grid<'_, T>
 where
     [T]: ToOwned<Owned = Vec<T>>,
@@ -34,7 +73,8 @@ where
 
 impl<'a> Grid<'a, u8> {
     /// Advent of Code frequently gives you maps drawn from characters. With this call, it can be
-    /// converted into a [`Grid`] with minimal calls and allocations.
+    /// converted into a [`Grid`] with minimal calls and allocations. Panics if the map isn't
+    /// rectangular; parse it with [`FromStr`] to get an error instead.
     pub fn from_input(input: &'a str) -> Self {
         Self::new(
             input
@@ -46,6 +86,65 @@ impl<'a> Grid<'a, u8> {
     }
 }
 
+impl FromStr for Grid<'static, u8> {
+    type Err = crate::Error;
+
+    /// Like [`Grid::from_input`], but owning its data, and returning an error rather than
+    /// panicking if the input isn't rectangular.
+    fn from_str(input: &str) -> crate::Result<Self> {
+        // Leading empty lines are trimmed, but still count for the line numbers in errors.
+        let skipped = input[..input.len() - input.trim_start().len()]
+            .matches('\n')
+            .count();
+        let rows: Vec<Cow<[u8]>> = input
+            .trim()
+            .lines()
+            .map(|line| Cow::Owned(line.as_bytes().to_vec()))
+            .collect();
+
+        let width = rows
+            .first()
+            .ok_or_else(|| crate::Error::parse("empty grid"))?
+            .len();
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            let message = format!("row is {} wide instead of {width}", rows[y].len());
+            let column = rows[y].len().min(width) + 1;
+            return Err(crate::Error::parse(message).at(skipped + y + 1, column));
+        }
+        Ok(Self::new(rows))
+    }
+}
+
+impl fmt::Display for Grid<'_, u8> {
+    /// Writes the grid as the text it was parsed from by [`Grid::from_input`], without the
+    /// trailing newline.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.data.iter().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Grid<'_, u8> {
+    /// Shows the size of the grid, and its rows as text; use `{:#?}` to get a row per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<_> = self
+            .data
+            .iter()
+            .map(|row| String::from_utf8_lossy(row))
+            .collect();
+        f.debug_struct("Grid")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("rows", &rows)
+            .finish()
+    }
+}
+
 impl<T> Grid<'static, T>
 where
     [T]: ToOwned<Owned = Vec<T>>,
@@ -94,6 +193,41 @@ where
         Self::new(iters.map(|iter| Cow::Owned(iter.collect())).collect())
     }
 
+    /// Renders the grid as text, with one line per row, and the character for each cell given by
+    /// `f`. For a `Grid<u8>` of characters, [`Display`](fmt::Display) does this already.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        self.render_by_position(|_, cell| f(cell))
+    }
+
+    /// Like [`Grid::render`], but with `mark` in place of the cells at the positions in
+    /// `highlight`, for example to show a path through the grid.
+    pub fn render_highlighted(
+        &self,
+        highlight: &HashSet<(i32, i32)>,
+        mark: char,
+        mut f: impl FnMut(&T) -> char,
+    ) -> String {
+        self.render_by_position(|p, cell| {
+            if highlight.contains(&p) {
+                mark
+            } else {
+                f(cell)
+            }
+        })
+    }
+
+    /// Renders the grid like [`Grid::render`], with `f` also getting the position of each cell.
+    fn render_by_position(&self, mut f: impl FnMut((i32, i32), &T) -> char) -> String {
+        let rows: Vec<String> = (self.data.iter().enumerate())
+            .map(|(y, row)| {
+                (row.iter().enumerate())
+                    .map(|(x, cell)| f((x as i32, y as i32), cell))
+                    .collect()
+            })
+            .collect();
+        rows.join("\n")
+    }
+
     /// Gets the width of the grid.
     pub fn width(&self) -> usize {
         self.width
@@ -189,6 +323,214 @@ where
             .get_mut(y)
             .and_then(|line| line.to_mut().get_mut(x))
     }
+
+    /// Maps `p` to the position of the cell it refers to under `addressing`, if any.
+    pub fn resolve(&self, p: (i32, i32), addressing: Addressing) -> Option<(i32, i32)> {
+        addressing.resolve(p, self.width, self.height)
+    }
+
+    /// Get a reference to the cell at position `p`, with positions outside of the grid treated
+    /// according to `addressing`.
+    pub fn get_with(&self, p: (i32, i32), addressing: Addressing) -> Option<&T> {
+        self.get(self.resolve(p, addressing)?)
+    }
+
+    /// Get a reference to the cell at position `p`, treating the grid as being repeated infinitely
+    /// in all directions. Also returns which copy of the grid (tile) `p` is in, where the actual
+    /// grid is (0, 0), the one to the left of it (-1, 0), and so on.
+    pub fn get_tiled(&self, p: (i32, i32)) -> Option<(&T, (i32, i32))> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        let cell = self.get_with(p, Addressing::Wrap)?;
+        Some((cell, (p.0.div_euclid(width), p.1.div_euclid(height))))
+    }
+
+    /// Returns the grid upside down. Rows borrowed from the input stay borrowed.
+    pub fn flipped_vertically(&self) -> Grid<'a, T> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().rev().cloned().collect(),
+        }
+    }
+
+    /// Returns the rectangle of the grid with the top left corner at `origin` and the given size,
+    /// which becomes (0, 0) in the new grid. Rows borrowed from the input stay borrowed. Panics if
+    /// the rectangle isn't entirely within the grid.
+    pub fn crop(&self, origin: (i32, i32), width: usize, height: usize) -> Grid<'a, T> {
+        let (x, y) = self.rectangle(origin, width, height);
+        let data = self.data[y..y + height]
+            .iter()
+            .map(|row| match row {
+                Cow::Borrowed(row) => Cow::Borrowed(&row[x..x + width]),
+                Cow::Owned(row) => Cow::Owned(row[x..x + width].to_owned()),
+            })
+            .collect();
+
+        Grid {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Returns a view of the rectangle of the grid with the top left corner at `origin` and the
+    /// given size, without copying anything. Within the view, coordinates are relative to
+    /// `origin`. Panics if the rectangle isn't entirely within the grid.
+    pub fn view(&self, origin: (i32, i32), width: usize, height: usize) -> View<'_, 'a, T> {
+        self.rectangle(origin, width, height);
+        View {
+            grid: self,
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// Checks that the given rectangle is within the grid, and returns its origin as indices.
+    fn rectangle(&self, origin: (i32, i32), width: usize, height: usize) -> (usize, usize) {
+        let x = usize::try_from(origin.0).expect("origin within the grid");
+        let y = usize::try_from(origin.1).expect("origin within the grid");
+        assert!(
+            x + width <= self.width && y + height <= self.height,
+            "rectangle within the grid"
+        );
+        (x, y)
+    }
+
+    /// An iterator over the neighbours of `p` in the directions `dirs` that are within the grid.
+    pub fn neighbours<'b>(
+        &'b self,
+        p: (i32, i32),
+        dirs: &'b [Dir],
+    ) -> impl Iterator<Item = (i32, i32)> + 'b {
+        dirs.iter()
+            .map(move |d| p + d)
+            .filter(|&q| self.get(q).is_some())
+    }
+
+    /// Finds all cells reachable from `start` through steps in `dirs`, where a step from one cell
+    /// to a neighbouring one is only possible if `connected` returns true for them (in that
+    /// order). Returns their positions in the order they were reached, starting with `start`.
+    pub fn flood_fill(
+        &self,
+        start: (i32, i32),
+        dirs: &[Dir],
+        connected: impl FnMut(&T, &T) -> bool,
+    ) -> Vec<(i32, i32)> {
+        let mut seen = Grid::<Option<usize>>::from_elem(self.width, self.height, None);
+        self.bfs([start], dirs, connected, &mut seen)
+    }
+
+    /// Finds the distance (in steps, see [`Grid::flood_fill`]) from the closest of `sources` to
+    /// every cell; `None` for cells that can't be reached at all.
+    pub fn distances(
+        &self,
+        sources: impl IntoIterator<Item = (i32, i32)>,
+        dirs: &[Dir],
+        connected: impl FnMut(&T, &T) -> bool,
+    ) -> Grid<'static, Option<usize>> {
+        let mut distances = Grid::<Option<usize>>::from_elem(self.width, self.height, None);
+        self.bfs(sources, dirs, connected, &mut distances);
+        distances
+    }
+
+    /// Splits the grid into regions of horizontally or vertically adjacent cells for which `same`
+    /// returns true (which should be symmetric). Returns a grid with the index of the region each
+    /// cell belongs to, alongside the regions in the order of their first cell.
+    pub fn regions(
+        &self,
+        mut same: impl FnMut(&T, &T) -> bool,
+    ) -> (Grid<'static, usize>, Vec<Region>) {
+        let mut seen = Grid::<Option<usize>>::from_elem(self.width, self.height, None);
+        let mut labels = Grid::<usize>::from_elem(self.width, self.height, usize::MAX);
+        let mut regions = vec![];
+
+        for p in self.coordinates() {
+            if seen[p].is_some() {
+                continue;
+            }
+
+            let label = regions.len();
+            let cells = self.bfs([p], &CARDINAL, &mut same, &mut seen);
+            for &q in &cells {
+                labels[q] = label;
+            }
+
+            // Every side of a cell facing out of the region is a piece of fence. Going along a
+            // straight side, only the first piece starts a new one; the others continue the
+            // fence of the cell to their left (as seen facing outwards).
+            let inside = |q| labels.get(q) == Some(&label);
+            let (mut perimeter, mut sides) = (0, 0);
+            for &q in &cells {
+                for d in CARDINAL.into_iter().filter(|&d| !inside(q + d)) {
+                    perimeter += 1;
+                    let left = q + d.left();
+                    if !inside(left) || inside(left + d) {
+                        sides += 1;
+                    }
+                }
+            }
+
+            regions.push(Region {
+                cells,
+                perimeter,
+                sides,
+            });
+        }
+
+        (labels, regions)
+    }
+
+    /// A breadth-first search from `sources`, as described in [`Grid::flood_fill`], which records
+    /// the distance to every reached cell in `distances`. Cells that already have a distance are
+    /// treated as reached before. Returns the newly reached cells in order.
+    fn bfs(
+        &self,
+        sources: impl IntoIterator<Item = (i32, i32)>,
+        dirs: &[Dir],
+        mut connected: impl FnMut(&T, &T) -> bool,
+        distances: &mut Grid<'_, Option<usize>>,
+    ) -> Vec<(i32, i32)> {
+        let mut reached = vec![];
+        let mut queue = VecDeque::new();
+        for p in sources {
+            if distances.get(p) == Some(&None) {
+                distances[p] = Some(0);
+                reached.push(p);
+                queue.push_back((p, 0));
+            }
+        }
+
+        while let Some((p, distance)) = queue.pop_front() {
+            for q in self.neighbours(p, dirs) {
+                if distances[q].is_none() && connected(&self[p], &self[q]) {
+                    distances[q] = Some(distance + 1);
+                    reached.push(q);
+                    queue.push_back((q, distance + 1));
+                }
+            }
+        }
+
+        reached
+    }
+}
+
+/// A region of connected cells, as found by [`Grid::regions`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Region {
+    /// The positions of all cells in the region.
+    pub cells: Vec<(i32, i32)>,
+    /// The number of cell sides that border on cells outside the region.
+    pub perimeter: usize,
+    /// The number of straight sides of the region's border, including those of holes.
+    pub sides: usize,
+}
+
+impl Region {
+    /// The number of cells in the region.
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
 }
 
 impl<T> Grid<'_, T>
@@ -205,4 +547,352 @@ where
     pub fn at(&self, p: (i32, i32)) -> Option<T> {
         self.get(p).cloned()
     }
+
+    /// Returns the grid mirrored along its main diagonal, so rows become columns.
+    pub fn transposed(&self) -> Grid<'static, T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Returns the grid rotated clockwise by 90 degrees.
+    pub fn rotated_right(&self) -> Grid<'static, T> {
+        let bottom = self.height as i32 - 1;
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, bottom - x)].clone()
+        })
+    }
+
+    /// Returns the grid rotated counter-clockwise by 90 degrees.
+    pub fn rotated_left(&self) -> Grid<'static, T> {
+        let right = self.width as i32 - 1;
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(right - y, x)].clone()
+        })
+    }
+
+    /// Returns the grid rotated clockwise by `turns` times 90 degrees. Negative values rotate
+    /// counter-clockwise.
+    pub fn rotated(&self, turns: i32) -> Grid<'static, T> {
+        let (right, bottom) = (self.width as i32 - 1, self.height as i32 - 1);
+        match turns.rem_euclid(4) {
+            0 => Grid::from_fn(self.width, self.height, |p| self[p].clone()),
+            1 => self.rotated_right(),
+            2 => Grid::from_fn(self.width, self.height, |(x, y)| {
+                self[(right - x, bottom - y)].clone()
+            }),
+            _ => self.rotated_left(),
+        }
+    }
+
+    /// Returns the grid mirrored left to right.
+    pub fn flipped_horizontally(&self) -> Grid<'static, T> {
+        let right = self.width as i32 - 1;
+        Grid::from_fn(self.width, self.height, |(x, y)| {
+            self[(right - x, y)].clone()
+        })
+    }
+
+    /// Returns all 8 ways the grid can be oriented through rotations and flips: first the four
+    /// rotations (see [`Grid::rotated`]), then the same for the horizontally flipped grid.
+    pub fn orientations(&self) -> Vec<Grid<'static, T>> {
+        let flipped = self.flipped_horizontally();
+        (0..8)
+            .map(|i| match i {
+                0..4 => self.rotated(i),
+                _ => flipped.rotated(i - 4),
+            })
+            .collect()
+    }
+}
+
+/// A rectangular part of a [`Grid`], as created by [`Grid::view`]. Coordinates are relative to
+/// its top left corner.
+pub struct View<'g, 'a, T>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    grid: &'g Grid<'a, T>,
+    origin: (i32, i32),
+    width: usize,
+    height: usize,
+}
+
+impl<T> std::ops::Index<(i32, i32)> for View<'_, '_, T>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    type Output = T;
+
+    fn index(&self, index: (i32, i32)) -> &Self::Output {
+        self.get(index).expect("index in bounds")
+    }
+}
+
+impl<'g, T> View<'g, '_, T>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    /// Gets the width of the view.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the height of the view.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get a reference to the cell at position `p`, relative to the view.
+    pub fn get(&self, p: (i32, i32)) -> Option<&'g T> {
+        let inside =
+            (0..self.width as i32).contains(&p.0) && (0..self.height as i32).contains(&p.1);
+        inside
+            .then(|| self.grid.get((self.origin.0 + p.0, self.origin.1 + p.1)))
+            .flatten()
+    }
+
+    /// An iterator over all coordinates in the view.
+    pub fn coordinates(&self) -> impl Iterator<Item = (i32, i32)> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x as i32, y as i32)))
+    }
+
+    /// An iterator over all coordinate/element pairs in the view.
+    pub fn iter_with_position(&self) -> impl Iterator<Item = ((i32, i32), &'g T)> + '_ {
+        self.coordinates()
+            .map(move |p| (p, self.get(p).expect("coordinate in bounds")))
+    }
+
+    /// Counts how many cells match `f`.
+    pub fn count(&self, mut f: impl FnMut(&T) -> bool) -> usize {
+        self.iter_with_position().filter(|(_, e)| f(e)).count()
+    }
+
+    /// Copies the contents of the view into a new grid.
+    pub fn to_grid(&self) -> Grid<'static, T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |p| self[p].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ALL_DIRS;
+
+    fn digit(n: usize) -> char {
+        char::from_digit(n as u32, 10).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        let input = "\n#..#\n.#.#\n..##\n";
+        let grid: Grid<u8> = input.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid.to_string(), input.trim());
+        assert_eq!(Grid::from_input(input).to_string(), input.trim());
+    }
+
+    #[test]
+    fn parse_reports_uneven_rows() {
+        let error = "\n\n#..\n#.\n".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!(
+            error,
+            crate::Error::parse("row is 2 wide instead of 3").at(4, 3)
+        );
+        let error = "#..\n#...\n".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!(
+            error,
+            crate::Error::parse("row is 4 wide instead of 3").at(2, 4)
+        );
+        assert!("  \n".parse::<Grid<u8>>().is_err());
+    }
+
+    #[test]
+    fn render_and_debug() {
+        let grid = Grid::from_input("#.\n.#");
+        assert_eq!(
+            grid.render(|&c| if c == b'#' { 'X' } else { ' ' }),
+            "X \n X"
+        );
+        let path = HashSet::from([(1, 0)]);
+        assert_eq!(
+            grid.render_highlighted(&path, 'O', |&c| c as char),
+            "#O\n.#"
+        );
+        assert_eq!(
+            format!("{grid:?}"),
+            r##"Grid { width: 2, height: 2, rows: ["#.", ".#"] }"##
+        );
+    }
+
+    /// The area, perimeter and number of sides of every region, in order.
+    fn region_sizes(input: &str) -> Vec<(usize, usize, usize)> {
+        let (_, regions) = Grid::from_input(input).regions(|a, b| a == b);
+        regions
+            .iter()
+            .map(|r| (r.area(), r.perimeter, r.sides))
+            .collect()
+    }
+
+    #[test]
+    fn regions() {
+        let grid = Grid::from_input("AAAA\nBBCD\nBBCC\nEEEC");
+        let (labels, regions) = grid.regions(|a, b| a == b);
+        assert_eq!(labels.render(|&l| digit(l)), "0000\n1123\n1122\n4442");
+        assert_eq!(regions[3].cells, [(3, 1)]);
+        assert_eq!(
+            region_sizes("AAAA\nBBCD\nBBCC\nEEEC"),
+            [(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+    }
+
+    #[test]
+    fn region_with_hole() {
+        assert_eq!(region_sizes("AAA\nABA\nAAA"), [(8, 16, 8), (1, 4, 4)]);
+        // The two B's only touch diagonally, so they are separate regions, and the fence of the A
+        // region goes around each of them.
+        assert_eq!(
+            region_sizes("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA"),
+            [(28, 40, 12), (4, 8, 4), (4, 8, 4)]
+        );
+    }
+
+    #[test]
+    fn distances_from_multiple_sources() {
+        let grid = Grid::from_input(".#...\n.#.#.\n...#.");
+        let distances = grid.distances([(0, 0), (4, 0)], &CARDINAL, |_, &to| to != b'#');
+        assert_eq!(
+            distances.render(|d| d.map_or('#', digit)),
+            "0#210\n1#3#1\n234#2"
+        );
+    }
+
+    #[test]
+    fn flood_fill() {
+        let grid = Grid::from_input("..#..\n..#..\n###.#");
+        let open = |_: &u8, &to: &u8| to == b'.';
+
+        let filled = grid.flood_fill((0, 0), &CARDINAL, open);
+        assert_eq!(filled, [(0, 0), (1, 0), (0, 1), (1, 1)]);
+
+        let mut filled = grid.flood_fill((4, 1), &ALL_DIRS, open);
+        assert_eq!(filled[0], (4, 1));
+        filled.sort();
+        assert_eq!(filled, [(3, 0), (3, 1), (3, 2), (4, 0), (4, 1)]);
+
+        // Steps only go where `connected` allows; here, only up by one.
+        let grid = Grid::from_input("0123\n0320");
+        let filled = grid.flood_fill((0, 0), &CARDINAL, |from, to| *to == from + 1);
+        assert_eq!(filled, [(0, 0), (1, 0), (2, 0), (3, 0)]);
+    }
+
+    #[test]
+    fn transforms_of_non_square_grid() {
+        let grid = Grid::from_input("abc\ndef");
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotated_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotated_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotated(2).to_string(), "fed\ncba");
+        assert_eq!(
+            grid.rotated(-1).to_string(),
+            grid.rotated_left().to_string()
+        );
+        assert_eq!(
+            grid.rotated(5).to_string(),
+            grid.rotated_right().to_string()
+        );
+        assert_eq!(grid.rotated(4).to_string(), grid.to_string());
+        assert_eq!(grid.flipped_horizontally().to_string(), "cba\nfed");
+        assert_eq!(grid.flipped_vertically().to_string(), "def\nabc");
+    }
+
+    #[test]
+    fn orientations_are_distinct() {
+        let grid = Grid::from_input("abc\ndef");
+        let orientations = grid.orientations();
+        assert_eq!(orientations.len(), 8);
+
+        let distinct: HashSet<_> = orientations.iter().map(|o| o.to_string()).collect();
+        assert_eq!(distinct.len(), 8);
+        for (i, orientation) in orientations.iter().enumerate() {
+            let size = (orientation.width(), orientation.height());
+            assert_eq!(size, if i % 2 == 0 { (3, 2) } else { (2, 3) });
+        }
+        assert_eq!(orientations[0].to_string(), "abc\ndef");
+        assert_eq!(orientations[4].to_string(), "cba\nfed");
+    }
+
+    #[test]
+    fn crop() {
+        let grid = Grid::from_input("abcd\nefgh\nijkl");
+        assert_eq!(grid.crop((1, 1), 2, 2).to_string(), "fg\njk");
+        assert_eq!(grid.crop((0, 2), 4, 1).to_string(), "ijkl");
+        assert_eq!(grid.crop((0, 0), 4, 3).to_string(), grid.to_string());
+
+        let cropped = grid.crop((3, 0), 1, 3);
+        assert_eq!((cropped.width(), cropped.height()), (1, 3));
+        assert_eq!(cropped.get((0, 2)), Some(&b'l'));
+        assert_eq!(cropped.get((1, 0)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "rectangle within the grid")]
+    fn crop_out_of_bounds() {
+        Grid::from_input("abcd\nefgh").crop((3, 0), 2, 1);
+    }
+
+    #[test]
+    fn view() {
+        let grid = Grid::from_input("abcd\nefgh\nijkl");
+        let view = grid.view((1, 0), 3, 2);
+        assert_eq!((view.width(), view.height()), (3, 2));
+        assert_eq!(view[(0, 0)], b'b');
+        assert_eq!(view.get((2, 1)), Some(&b'h'));
+        assert_eq!(view.get((3, 0)), None);
+        assert_eq!(view.get((0, 2)), None);
+        assert_eq!(view.get((-1, 0)), None);
+        assert_eq!(view.count(|&c| c > b'c'), 4);
+        assert_eq!(view.to_grid().to_string(), "bcd\nfgh");
+    }
+
+    #[test]
+    #[should_panic(expected = "rectangle within the grid")]
+    fn view_out_of_bounds() {
+        Grid::from_input("abcd\nefgh").view((0, 1), 4, 2);
+    }
+
+    #[test]
+    fn addressing() {
+        let grid = Grid::from_input("abc\ndef");
+        let resolve = |p, addressing| grid.resolve(p, addressing);
+
+        assert_eq!(resolve((2, 1), Addressing::Bounded), Some((2, 1)));
+        assert_eq!(resolve((3, 0), Addressing::Bounded), None);
+        assert_eq!(resolve((-1, 0), Addressing::Bounded), None);
+
+        assert_eq!(resolve((-1, 0), Addressing::Wrap), Some((2, 0)));
+        assert_eq!(resolve((3, 2), Addressing::Wrap), Some((0, 0)));
+        assert_eq!(resolve((-4, -3), Addressing::Wrap), Some((2, 1)));
+
+        assert_eq!(resolve((-5, 1), Addressing::Clamp), Some((0, 1)));
+        assert_eq!(resolve((7, -2), Addressing::Clamp), Some((2, 0)));
+        assert_eq!(grid.get_with((-1, -1), Addressing::Wrap), Some(&b'f'));
+        assert_eq!(grid.get_with((9, 9), Addressing::Clamp), Some(&b'f'));
+
+        for addressing in [Addressing::Bounded, Addressing::Wrap, Addressing::Clamp] {
+            assert_eq!(addressing.resolve((0, 0), 0, 0), None);
+        }
+    }
+
+    #[test]
+    fn get_tiled() {
+        let grid = Grid::from_input("abc\ndef");
+        assert_eq!(grid.get_tiled((1, 0)), Some((&b'b', (0, 0))));
+        assert_eq!(grid.get_tiled((-1, 0)), Some((&b'c', (-1, 0))));
+        assert_eq!(grid.get_tiled((3, 2)), Some((&b'a', (1, 1))));
+        assert_eq!(grid.get_tiled((5, -1)), Some((&b'f', (1, -1))));
+        assert_eq!(grid.get_tiled((-4, -3)), Some((&b'f', (-2, -2))));
+    }
 }
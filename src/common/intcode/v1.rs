@@ -5,6 +5,8 @@
 
 use std::collections::VecDeque;
 
+use crate::common::parse_csv;
+
 pub type Int = i32;
 
 /// An Intcode program, as per the puzzle description.
@@ -42,12 +44,8 @@ impl Program {
     /// Builds a new [`Program`], parsing the code from a puzzle input, and populating 'stdin'
     /// for the program with `input`.
     pub fn new(code: &str, input: impl IntoIterator<Item = Int>) -> crate::Result<Self> {
-        fn parse(input: &str) -> Result<Vec<Int>, impl std::error::Error> {
-            input.trim().split(',').map(|n| n.parse::<Int>()).collect()
-        }
-
         Ok(Self {
-            code: parse(code)?,
+            code: parse_csv(code)?,
             input: input.into_iter().collect(),
             ..Default::default()
         })
@@ -117,7 +115,7 @@ fn decompose(code: Int) -> crate::Result<(Op, Mode, Mode, Mode)> {
 }
 
 impl TryFrom<Int> for Op {
-    type Error = Box<dyn std::error::Error>;
+    type Error = crate::Error;
 
     fn try_from(value: Int) -> Result<Self, Self::Error> {
         Ok(match value {
@@ -130,19 +128,27 @@ impl TryFrom<Int> for Op {
             7 => Op::Arith(|a, b| Int::from(a < b)),
             8 => Op::Arith(|a, b| Int::from(a == b)),
             99 => Op::Halt,
-            _ => return Err(format!("unknown opcode {value}").into()),
+            _ => {
+                return Err(crate::Error::invalid_input(format!(
+                    "unknown opcode {value}"
+                )))
+            }
         })
     }
 }
 
 impl TryFrom<Int> for Mode {
-    type Error = Box<dyn std::error::Error>;
+    type Error = crate::Error;
 
     fn try_from(value: Int) -> Result<Self, Self::Error> {
         Ok(match value {
             0 => Mode::Position,
             1 => Mode::Immediate,
-            _ => return Err(format!("unknown addressing mode {value}").into()),
+            _ => {
+                return Err(crate::Error::invalid_input(format!(
+                    "unknown addressing mode {value}"
+                )))
+            }
         })
     }
 }
@@ -7,6 +7,8 @@
 
 use std::collections::VecDeque;
 
+use crate::common::parse_csv;
+
 pub type Int = i64;
 
 /// An Intcode program, as per the puzzle description.
@@ -48,12 +50,8 @@ impl Program {
     /// Builds a new [`Program`], parsing the code from a puzzle input, and populating 'stdin'
     /// for the program with `input`.
     pub fn new(code: &str) -> crate::Result<Self> {
-        fn parse(input: &str) -> Result<Vec<Int>, impl std::error::Error> {
-            input.trim().split(',').map(|n| n.parse::<Int>()).collect()
-        }
-
         Ok(Self {
-            code: parse(code)?,
+            code: parse_csv(code)?,
             ..Default::default()
         })
     }
@@ -157,7 +155,7 @@ fn decompose(code: Int) -> crate::Result<(Op, Mode, Mode, Mode)> {
 }
 
 impl TryFrom<Int> for Op {
-    type Error = Box<dyn std::error::Error>;
+    type Error = crate::Error;
 
     fn try_from(value: Int) -> Result<Self, Self::Error> {
         Ok(match value {
@@ -171,20 +169,28 @@ impl TryFrom<Int> for Op {
             8 => Op::Arith(|a, b| Int::from(a == b)),
             9 => Op::ChangeRelativeBase,
             99 => Op::Halt,
-            _ => return Err(format!("unknown opcode {value}").into()),
+            _ => {
+                return Err(crate::Error::invalid_input(format!(
+                    "unknown opcode {value}"
+                )))
+            }
         })
     }
 }
 
 impl TryFrom<Int> for Mode {
-    type Error = Box<dyn std::error::Error>;
+    type Error = crate::Error;
 
     fn try_from(value: Int) -> Result<Self, Self::Error> {
         Ok(match value {
             0 => Mode::Position,
             1 => Mode::Immediate,
             2 => Mode::Relative,
-            _ => return Err(format!("unknown addressing mode {value}").into()),
+            _ => {
+                return Err(crate::Error::invalid_input(format!(
+                    "unknown addressing mode {value}"
+                )))
+            }
         })
     }
 }
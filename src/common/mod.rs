@@ -1,4 +1,4 @@
-// Large swathes of solutions may be commented out any given time, which
+// Large swathes of solutions may be disabled through features at any given time, which
 // produces spurious unused warnings from this module.
 #![allow(unused)]
 
@@ -11,9 +11,61 @@ pub mod astar;
 mod dir;
 mod grid;
 pub mod intcode;
+mod param;
+mod sparse_grid;
 
 pub use dir::{Dir, ALL_DIRS, CARDINAL};
-pub use grid::Grid;
+pub use grid::{Addressing, Grid, Region, View};
+pub use param::{param, param_override, set_param};
+pub use sparse_grid::SparseGrid;
+
+/// Splits `input` at its first blank line, like `split_once("\n\n")`, but working with both `\n`
+/// and `\r\n` line endings.
+pub fn split_section(input: &str) -> Option<(&str, &str)> {
+    ["\n\n", "\r\n\r\n"]
+        .into_iter()
+        .filter_map(|separator| input.split_once(separator))
+        .min_by_key(|(section, _)| section.len())
+}
+
+/// Returns an iterator over the sections of `input` separated by blank lines, like
+/// `split("\n\n")`, but working with both `\n` and `\r\n` line endings.
+pub fn sections(mut input: &str) -> impl Iterator<Item = &str> {
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        match split_section(input) {
+            Some((section, rest)) => {
+                input = rest;
+                Some(section)
+            }
+            None => {
+                done = true;
+                Some(input)
+            }
+        }
+    })
+}
+
+/// Parses a single line of comma-separated values, like an Intcode program. Errors point at the
+/// column of the value that failed to parse.
+pub fn parse_csv<T>(input: &str) -> crate::Result<Vec<T>>
+where
+    T: std::str::FromStr,
+    crate::Error: From<T::Err>,
+{
+    let mut column = input.len() - input.trim_start().len() + 1;
+    (input.trim().split(','))
+        .map(|n| {
+            let value = n.parse::<T>();
+            let error_column = column;
+            column += n.len() + 1;
+            value.map_err(|e| crate::Error::from(e).at(1, error_column))
+        })
+        .collect()
+}
 
 /// Returns an iterator over all the bit indices that are a one.
 pub fn one_indices(v: u32) -> impl Iterator<Item = usize> {
@@ -179,23 +231,9 @@ pub fn pixel_display(
 
 /// Constructs a [pixel display](pixel_display) banner from a set of points.
 pub fn pixel_display_from_set(points: HashSet<(i32, i32)>) -> String {
-    let (lx, ly, hx, hy) = {
-        let (mut lx, mut ly, mut hx, mut hy) = (i32::MAX, i32::MAX, i32::MIN, i32::MIN);
-        for &(x, y) in &points {
-            lx = lx.min(x);
-            ly = ly.min(y);
-            hx = hx.max(x);
-            hy = hy.max(y);
-        }
-        (lx, ly, hx, hy)
-    };
-
-    let width = (1 + hx - lx) as usize;
-    let height = (1 + hy - ly) as usize;
-
-    pixel_display(width, height, |x, y| {
-        points.contains(&(lx + x as i32, ly + y as i32))
-    })
+    let grid: SparseGrid<()> = points.into_iter().map(|p| (p, ())).collect();
+    let display = grid.render(|cell| if cell.is_some() { '#' } else { '.' });
+    format!("\n{display}")
 }
 
 /// Performs a breadth-first search of a value space, returning the
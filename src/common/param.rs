@@ -0,0 +1,34 @@
+//! Named parameters of solutions, like a step count or a grid size, which can be changed at
+//! runtime (through the `repl` subcommand) instead of having to recompile for every experiment.
+
+use std::{collections::BTreeMap, str::FromStr, sync::RwLock};
+
+/// The values of all overridden parameters, by name.
+static OVERRIDES: RwLock<BTreeMap<String, String>> = RwLock::new(BTreeMap::new());
+
+/// Returns the value of the parameter `name`: the override, if there is one that parses as `T`,
+/// or `default` otherwise.
+///
+/// Solutions use this in place of a constant, as in `param("steps", 64)`.
+pub fn param<T: FromStr>(name: &str, default: T) -> T {
+    let overrides = OVERRIDES.read().unwrap_or_else(|e| e.into_inner());
+    overrides
+        .get(name)
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Overrides the parameter `name` with `value`, or removes its override if `value` is `None`.
+pub fn set_param(name: &str, value: Option<String>) {
+    let mut overrides = OVERRIDES.write().unwrap_or_else(|e| e.into_inner());
+    match value {
+        Some(value) => overrides.insert(name.to_string(), value),
+        None => overrides.remove(name),
+    };
+}
+
+/// Returns the override of the parameter `name`, if any.
+pub fn param_override(name: &str) -> Option<String> {
+    let overrides = OVERRIDES.read().unwrap_or_else(|e| e.into_inner());
+    overrides.get(name).cloned()
+}
@@ -0,0 +1,219 @@
+//! A 2D grid without fixed bounds, for when it's not known up front how far things go.
+
+use std::collections::HashMap;
+
+use super::{Dir, Grid};
+
+/// A 2D grid without fixed bounds, which only stores the cells that have been set. Tracks the
+/// bounding box of all cells as they are inserted.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i32, i32), T>,
+    /// The smallest and largest coordinates of any cell, if there are any.
+    bounds: Option<((i32, i32), (i32, i32))>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> FromIterator<((i32, i32), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i32, i32), T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> std::ops::Index<(i32, i32)> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: (i32, i32)) -> &Self::Output {
+        self.get(index).expect("cell is set")
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty grid.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of cells that are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Whether no cells are set.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Get a reference to the cell at position `p`, if it is set.
+    pub fn get(&self, p: (i32, i32)) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    /// Get a mutable reference to the cell at position `p`, if it is set.
+    pub fn get_mut(&mut self, p: (i32, i32)) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    /// Whether the cell at position `p` is set.
+    pub fn contains(&self, p: (i32, i32)) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    /// Sets the cell at position `p` to `value`, and returns its previous value, if any.
+    pub fn insert(&mut self, p: (i32, i32), value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((low, high)) => (
+                (low.0.min(p.0), low.1.min(p.1)),
+                (high.0.max(p.0), high.1.max(p.1)),
+            ),
+            None => (p, p),
+        });
+        self.cells.insert(p, value)
+    }
+
+    /// Unsets the cell at position `p`, and returns its value, if any. To keep this cheap, the
+    /// bounds don't shrink; see [`SparseGrid::fit_bounds`].
+    pub fn remove(&mut self, p: (i32, i32)) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    /// The smallest and largest coordinates (inclusive) of all cells that have been set. After
+    /// cells are removed, this may be larger than necessary.
+    pub fn bounds(&self) -> Option<((i32, i32), (i32, i32))> {
+        self.bounds
+    }
+
+    /// Shrinks the bounds to the cells that are currently set.
+    pub fn fit_bounds(&mut self) {
+        let cells = std::mem::take(&mut self.cells);
+        self.bounds = None;
+        self.extend(cells);
+    }
+
+    /// An iterator over the neighbours of `p` in the directions `dirs` that are set.
+    pub fn neighbours<'b>(
+        &'b self,
+        p: (i32, i32),
+        dirs: &'b [Dir],
+    ) -> impl Iterator<Item = (i32, i32)> + 'b {
+        dirs.iter()
+            .map(move |d| p + d)
+            .filter(|&q| self.contains(q))
+    }
+
+    /// An iterator over all coordinate/element pairs in the grid, in no particular order.
+    pub fn iter_with_position(&self) -> impl Iterator<Item = ((i32, i32), &T)> + '_ {
+        self.cells.iter().map(|(&p, value)| (p, value))
+    }
+
+    /// Renders the cells within the bounds as text, with one line per row, and the character for
+    /// each cell given by `f`; its argument is `None` for cells that aren't set.
+    pub fn render(&self, mut f: impl FnMut(Option<&T>) -> char) -> String {
+        let Some(((lx, ly), (hx, hy))) = self.bounds else {
+            return String::new();
+        };
+
+        let rows: Vec<String> = (ly..=hy)
+            .map(|y| (lx..=hx).map(|x| f(self.get((x, y)))).collect())
+            .collect();
+        rows.join("\n")
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Converts the cells within the bounds into a dense [`Grid`], with `empty` in every cell that
+    /// isn't set. Returns it alongside the position of its top left corner in this grid.
+    pub fn to_grid(&self, empty: T) -> (Grid<'static, T>, (i32, i32)) {
+        let ((lx, ly), (hx, hy)) = self.bounds.unwrap_or_default();
+        let (width, height) = match self.bounds {
+            Some(_) => ((hx - lx + 1) as usize, (hy - ly + 1) as usize),
+            None => (0, 0),
+        };
+
+        let grid = Grid::from_fn(width, height, |(x, y)| {
+            self.get((lx + x, ly + y)).unwrap_or(&empty).clone()
+        });
+        (grid, (lx, ly))
+    }
+}
+
+impl<T> Extend<((i32, i32), T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = ((i32, i32), T)>>(&mut self, iter: I) {
+        for (p, value) in iter {
+            self.insert(p, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(grid: &SparseGrid<char>) -> String {
+        grid.render(|c| c.copied().unwrap_or('.'))
+    }
+
+    #[test]
+    fn insert_grows_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert((2, 3), 'a');
+        assert_eq!(grid.bounds(), Some(((2, 3), (2, 3))));
+        grid.insert((-1, 5), 'b');
+        assert_eq!(grid.bounds(), Some(((-1, 3), (2, 5))));
+        assert_eq!(grid.insert((2, 3), 'c'), Some('a'));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(render(&grid), "...c\n....\nb...");
+    }
+
+    #[test]
+    fn remove_keeps_bounds_until_fitted() {
+        let mut grid: SparseGrid<char> = [((0, 0), 'a'), ((4, 2), 'b'), ((1, 1), 'c')]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.remove((4, 2)), Some('b'));
+        assert_eq!(grid.remove((4, 2)), None);
+        assert_eq!(grid.bounds(), Some(((0, 0), (4, 2))));
+
+        grid.fit_bounds();
+        assert_eq!(grid.bounds(), Some(((0, 0), (1, 1))));
+        assert_eq!(render(&grid), "a.\n.c");
+
+        grid.remove((0, 0));
+        grid.remove((1, 1));
+        grid.fit_bounds();
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn to_grid_with_negative_coordinates() {
+        let grid: SparseGrid<char> = [((-2, -1), 'a'), ((0, 1), 'b')].into_iter().collect();
+        let (dense, origin) = grid.to_grid(' ');
+        assert_eq!(origin, (-2, -1));
+        assert_eq!((dense.width(), dense.height()), (3, 3));
+        assert_eq!(dense[(0, 0)], 'a');
+        assert_eq!(dense[(2, 2)], 'b');
+        assert_eq!(dense[(1, 1)], ' ');
+    }
+
+    #[test]
+    fn empty_grid() {
+        let grid = SparseGrid::<char>::new();
+        assert_eq!(render(&grid), "");
+        let (dense, origin) = grid.to_grid(' ');
+        assert_eq!((dense.width(), dense.height()), (0, 0));
+        assert_eq!(origin, (0, 0));
+    }
+}
@@ -0,0 +1,135 @@
+//! The error type shared by all solutions, which lets the runner (and its JSON output) tell apart
+//! the different ways a solution can fail.
+
+use std::{fmt::Display, time::Duration};
+
+/// Everything that can go wrong while solving a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The input couldn't be parsed. The (1-based) position is given if known; see [`Error::at`].
+    Parse {
+        message: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    /// The input was parsed fine, but doesn't have the shape the solution expects; for example,
+    /// it's missing a section, or has too few lines.
+    InvalidInput(String),
+    /// The solution ran, but didn't find an answer.
+    NoSolution(String),
+    /// The solution isn't written yet.
+    Unimplemented,
+    /// The solution didn't finish in time. Only produced by the runner.
+    Timeout(Duration),
+    /// The solution panicked; holds the panic message. Only produced by the runner.
+    Panicked(String),
+    /// Anything that doesn't fit the other categories.
+    Other(String),
+}
+
+impl Error {
+    /// Creates a [`Error::Parse`] without a position.
+    pub fn parse(message: impl Into<String>) -> Self {
+        Error::Parse {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// Creates an [`Error::InvalidInput`].
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        Error::InvalidInput(message.into())
+    }
+
+    /// Creates an [`Error::NoSolution`].
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        Error::NoSolution(message.into())
+    }
+
+    /// Attaches a (1-based) position to a [`Error::Parse`]; other errors are returned unchanged.
+    pub fn at(self, line: usize, column: usize) -> Self {
+        match self {
+            Error::Parse { message, .. } => Error::Parse {
+                message,
+                line: Some(line),
+                column: Some(column),
+            },
+            error => error,
+        }
+    }
+
+    /// A short, stable name for the kind of error, as used in JSON output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::Parse { .. } => "parse",
+            Error::InvalidInput(_) => "invalid_input",
+            Error::NoSolution(_) => "no_solution",
+            Error::Unimplemented => "unimplemented",
+            Error::Timeout(_) => "timeout",
+            Error::Panicked(_) => "panicked",
+            Error::Other(_) => "other",
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse {
+                message,
+                line: Some(line),
+                column,
+            } => {
+                write!(f, "{message} (line {line}")?;
+                if let Some(column) = column {
+                    write!(f, ", column {column}")?;
+                }
+                write!(f, ")")
+            }
+            Error::Parse { message, .. } => write!(f, "{message}"),
+            Error::InvalidInput(message) | Error::NoSolution(message) => write!(f, "{message}"),
+            Error::Unimplemented => write!(f, "unimplemented"),
+            Error::Timeout(time) => write!(f, "timed out after {}s", time.as_secs_f64()),
+            Error::Panicked(message) | Error::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Plain messages, as solutions have always used with `?`, aren't categorised any further; the
+/// other kinds have to be constructed explicitly.
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Other(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::from(message.to_string())
+    }
+}
+
+/// Implements `From` for other error types, which are all put into the same category given by
+/// `make`, a function from the error message to an [`Error`].
+macro_rules! convert_errors {
+    ($make:path => $($error:ty),*) => {$(
+        impl From<$error> for Error {
+            fn from(error: $error) -> Self {
+                $make(error.to_string())
+            }
+        }
+    )*}
+}
+
+convert_errors!(Error::parse =>
+    std::num::ParseIntError,
+    std::num::ParseFloatError,
+    std::char::ParseCharError,
+    std::str::Utf8Error,
+    std::string::FromUtf8Error
+);
+convert_errors!(Error::InvalidInput => std::num::TryFromIntError);
+convert_errors!(Error::Other => std::fmt::Error, std::io::Error, regex::Error);
@@ -0,0 +1,47 @@
+//! A project containing my (Phil, narvius@gmail.com) solutions to Advent of Code, written in Rust.
+//! Many of those solutions do not represent my first time ever solving the problems, but rather,
+//! the best Rust solution I am able to write as of... time of writing.
+//!
+//! Besides powering the command line runner (`main.rs`), this library makes the shared code in
+//! [`common`] available to other tools, as well as all solutions compiled into it, through
+//! [`solutions`] and [`solution`].
+
+pub mod common;
+mod error;
+#[macro_use]
+mod registry;
+mod runner;
+
+pub use error::Error;
+pub use registry::{solution, solutions, years, SolutionInfo};
+pub use runner::run_from_cmd_args;
+
+events! {
+    2015 if "y2015" => aoc2015::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25};
+    2016 if "y2016" => aoc2016::{day01, day02, day03, day04, #[ignore] day05, day06, day07, day08, day09, day10, day11, day12, day13, #[ignore] day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25};
+    2017 if "y2017" => aoc2017::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25};
+    2018 if "y2018" => aoc2018::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, #[ignore] day21, #[ignore] day22, day23, day24, day25};
+    2019 if "y2019" => aoc2019::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25};
+    2020 if "y2020" => aoc2020::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25};
+    2021 if "y2021" => aoc2021::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, #[ignore] day19, day20, day21, day22, day23, day24, day25};
+    2022 if "y2022" => aoc2022::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, #[ignore] day16, day17, day18, #[ignore] day19, day20, day21, day22, day23, day24, day25};
+    2023 if "y2023" => aoc2023::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25};
+    2024 if "y2024" => aoc2024::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25};
+    2025 if "y2025" => aoc2025::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, #[ignore] day11};
+}
+
+/// During 0 or 1 argument invocations, this year is assumed. It's the latest year enabled through
+/// cargo features, so `cargo run --features y2019` defaults to whatever is newest among those.
+const ASSUMED_YEAR: usize = registry::latest_year(YEARS);
+
+/// The result type used by all solutions.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A single full solution consisting of two solution functions, the text input for them, and the
+/// source code of the file they're defined in.
+type Solution = (SolutionFn, SolutionFn, &'static str, &'static str);
+
+/// A single solution function. Note that despite what the signature implies, the actual
+/// implementations can return any type that implements [`Display`](std::fmt::Display), due
+/// to it being ran through [`format`] (inside the [`events`] macro).
+pub type SolutionFn = fn(&str) -> Result<String>;
@@ -0,0 +1,189 @@
+//! The registry of all solutions compiled into the crate, and the public API for looking them up.
+
+use crate::{Result, Solution, SolutionFn, CONTENTS};
+
+/// A single solution, as exposed to users of this library.
+#[derive(Clone, Copy, Debug)]
+pub struct SolutionInfo {
+    /// The year of the event the puzzle is from.
+    pub year: usize,
+    /// The day of the puzzle, from 1 to 25.
+    pub day: usize,
+    /// The functions solving the two parts, in order.
+    pub parts: [SolutionFn; 2],
+    /// The puzzle input embedded in the binary.
+    pub input: &'static str,
+    /// The source code of the file the solution is defined in.
+    pub source: &'static str,
+}
+
+impl SolutionInfo {
+    /// Runs `part` (0 or 1) on the embedded input.
+    pub fn solve(&self, part: usize) -> Result<String> {
+        (self.parts[part])(self.input)
+    }
+
+    /// The known answer for `part` (0 or 1), if one is recorded.
+    pub fn answer(&self, part: usize) -> Option<String> {
+        get_answer(self.year, self.day, part)
+    }
+}
+
+/// Returns all solutions compiled into the crate, ordered by year and day. Which years are
+/// included depends on the enabled cargo features.
+pub fn solutions() -> impl Iterator<Item = SolutionInfo> {
+    CONTENTS.iter().flat_map(|&(year, days, _)| {
+        days.iter()
+            .zip(1..)
+            .map(move |(&(one, two, input, source), day)| SolutionInfo {
+                year,
+                day,
+                parts: [one, two],
+                input,
+                source,
+            })
+    })
+}
+
+/// Returns the solution for `day` of `year`, if it's compiled into the crate.
+pub fn solution(year: usize, day: usize) -> Option<SolutionInfo> {
+    solutions().find(|info| info.year == year && info.day == day)
+}
+
+/// Returns all years that have solutions compiled into the crate, in order.
+pub fn years() -> impl Iterator<Item = usize> {
+    CONTENTS.iter().map(|v| v.0)
+}
+
+/// Gets the solution (a tuple of two functions, the text input and the source code) for a given
+/// `year` and `day`; or `None` if it doesn't exist.
+pub(crate) fn get_solution(year: usize, day: usize) -> Option<&'static Solution> {
+    if valid_input(year, day) {
+        CONTENTS
+            .iter()
+            .find_map(|v| (v.0 == year).then_some(v.1))
+            .and_then(|o| o.get(day - 1))
+    } else {
+        None
+    }
+}
+
+/// Gets the known answer for the given `year`, `day` and `part` (0 or 1) from the year's
+/// answers file; or `None` if it isn't recorded.
+pub(crate) fn get_answer(year: usize, day: usize, part: usize) -> Option<String> {
+    let answers = CONTENTS.iter().find_map(|v| (v.0 == year).then_some(v.2))?;
+    let answers: serde_json::Value = serde_json::from_str(answers).ok()?;
+    let answer = answers.get(format!("day{day:02}"))?.get(part)?;
+    answer.as_str().map(String::from)
+}
+
+/// Checks whether all input numbers are within their respective valid ranges.
+pub(crate) fn valid_input(year: usize, day: usize) -> bool {
+    CONTENTS.iter().any(|v| v.0 == year) && (1..=25).contains(&day)
+}
+
+/// Returns the latest of the given years, or 0 if there are none.
+pub const fn latest_year(years: &[usize]) -> usize {
+    let (mut i, mut latest) = (0, 0);
+    while i < years.len() {
+        if years[i] > latest {
+            latest = years[i];
+        }
+        i += 1;
+    }
+    latest
+}
+
+/// Expands into a static variable named CONTENTS that holds all solutions, a const YEARS listing
+/// the enabled years, as well as the `mod` tree that includes the relevant files and a `tests`
+/// tree with tests for each of them. The macro call itself contains that list of modules and
+/// files. Should be used within the root module.
+///
+/// Each year is only compiled in if the cargo feature named after `if` is enabled; see
+/// `Cargo.toml`.
+///
+/// Follows the conventions that:
+/// - solutions are in src/solutions/(module)/dayXX.rs
+/// - each dayXX.rs file contains two public functions called `one` and `two`
+/// - each one of those takes a `&str` argument and returns a [`Result<T>`](crate::Result)
+/// - the known answers for a year are in src/data/(module)/answers.json, as an object mapping
+///   each dayXX to a list of the two answers (see [`get_answer`]).
+/// - puzzle examples, if any, are in src/data/(module)/dayXX_examples.txt (see `examples`).
+///
+/// Attributes on a day (like `#[ignore] day05`) are applied to its answer tests; that's how
+/// particularly slow solutions are kept out of a plain `cargo test`.
+macro_rules! events {
+    ($($year:literal if $feature:literal => $module:ident::{$($(#[$attr:meta])* $day:ident),*};)*) => {
+        mod solutions {$(
+            #[cfg(feature = $feature)]
+            pub mod $module {$(
+                pub mod $day;
+            )*}
+        )*}
+
+        /// All years that are enabled through cargo features.
+        const YEARS: &[usize] = &[$(
+            #[cfg(feature = $feature)]
+            $year
+        ),*];
+
+        /// The full space of Advent of Code solutions.
+        static CONTENTS: &'static [(usize, &'static [Solution], &'static str)] = &[$(
+            #[cfg(feature = $feature)]
+            (
+                $year,
+                &[$((
+                    |input| $crate::solutions::$module::$day::one(input).map(|v| format!("{v}")),
+                    |input| $crate::solutions::$module::$day::two(input).map(|v| format!("{v}")),
+                    include_str!(concat!(
+                        "data/",
+                        stringify!($module),
+                        "/",
+                        stringify!($day),
+                        ".txt"
+                    )),
+                    include_str!(concat!(
+                        "solutions/",
+                        stringify!($module),
+                        "/",
+                        stringify!($day),
+                        ".rs"
+                    )),
+                )),*],
+                include_str!(concat!("data/", stringify!($module), "/answers.json")),
+            )
+        ),*];
+
+        /// One test module per solution, checking it against the puzzle examples (see
+        /// [`runner::examples`](crate::runner::examples)) and both parts against the known
+        /// answers (see [`runner::golden`](crate::runner::golden)).
+        #[cfg(test)]
+        mod tests {$(
+            #[cfg(feature = $feature)]
+            mod $module {$(
+                mod $day {
+                    #[test]
+                    fn examples() {
+                        $crate::runner::examples::check(
+                            $year,
+                            stringify!($module),
+                            stringify!($day),
+                        );
+                    }
+
+                    #[test]
+                    $(#[$attr])*
+                    fn one() {
+                        $crate::runner::golden::check($year, stringify!($day), 0);
+                    }
+
+                    #[test]
+                    $(#[$attr])*
+                    fn two() {
+                        $crate::runner::golden::check($year, stringify!($day), 1);
+                    }
+                }
+            )*}
+        )*}
+    }
+}
@@ -0,0 +1,124 @@
+//! Timing baselines, which allow noticing performance regressions between runs.
+
+use std::{collections::BTreeMap, path::Path, time::Duration};
+
+use super::Result;
+
+/// Stored run times for a set of solution parts, as kept in a baseline file.
+///
+/// The file is a JSON object mapping keys like `"2024-17a"` to run times in nanoseconds.
+#[derive(Default)]
+pub struct Baseline {
+    times: BTreeMap<String, u64>,
+}
+
+/// A comparison of a run time against the baseline.
+#[derive(Clone, Copy, Debug)]
+pub struct Comparison {
+    /// The run time stored in the baseline.
+    pub baseline: Duration,
+    /// The relative change in run time, in percent; positive if the new run was slower.
+    pub change: f64,
+    /// Whether the new run was slower by more than the allowed threshold.
+    pub regressed: bool,
+}
+
+impl Baseline {
+    /// Loads a baseline from the file at `path`. A missing file is treated as an empty baseline.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let times = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        Ok(Self { times })
+    }
+
+    /// Writes the baseline to the file at `path`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        Ok(std::fs::write(
+            path,
+            serde_json::to_string_pretty(&self.times)?,
+        )?)
+    }
+
+    /// Records the run time for the given solution part, replacing any previous one.
+    pub fn insert(&mut self, year: usize, day: usize, part: usize, time: Duration) {
+        self.times
+            .insert(key(year, day, part), time.as_nanos() as u64);
+    }
+
+    /// Compares `time` against the stored run time for the given solution part, if there is one.
+    /// Counts as a regression if `time` is more than `threshold` percent slower.
+    pub fn compare(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        time: Duration,
+        threshold: f64,
+    ) -> Option<Comparison> {
+        let baseline = Duration::from_nanos(*self.times.get(&key(year, day, part))?);
+        let change = if baseline.is_zero() {
+            0.0
+        } else {
+            100.0 * (time.as_secs_f64() / baseline.as_secs_f64() - 1.0)
+        };
+
+        Some(Comparison {
+            baseline,
+            change,
+            regressed: change > threshold,
+        })
+    }
+}
+
+/// The key used for a solution part in a baseline file.
+fn key(year: usize, day: usize, part: usize) -> String {
+    format!("{year:04}-{day:02}{}", super::record::PARTS[part])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline() -> Baseline {
+        let mut baseline = Baseline::default();
+        baseline.insert(2024, 17, 0, Duration::from_millis(100));
+        baseline
+    }
+
+    fn compare(millis: u64) -> Option<Comparison> {
+        baseline().compare(2024, 17, 0, Duration::from_millis(millis), 10.0)
+    }
+
+    #[test]
+    fn slower_than_threshold_regresses() {
+        let comparison = compare(120).unwrap();
+        assert_eq!(comparison.baseline, Duration::from_millis(100));
+        assert!((comparison.change - 20.0).abs() < 1e-9);
+        assert!(comparison.regressed);
+    }
+
+    #[test]
+    fn at_threshold_does_not_regress() {
+        let comparison = compare(110).unwrap();
+        assert!((comparison.change - 10.0).abs() < 1e-9);
+        assert!(!comparison.regressed);
+    }
+
+    #[test]
+    fn faster_does_not_regress() {
+        let comparison = compare(80).unwrap();
+        assert!((comparison.change + 20.0).abs() < 1e-9);
+        assert!(!comparison.regressed);
+    }
+
+    #[test]
+    fn missing_part_has_no_comparison() {
+        let baseline = baseline();
+        let time = Duration::from_millis(100);
+        assert!(baseline.compare(2024, 17, 1, time, 10.0).is_none());
+        assert!(baseline.compare(2024, 18, 0, time, 10.0).is_none());
+    }
+}
@@ -0,0 +1,131 @@
+//! Statistical benchmarking of solutions through repeated runs.
+
+use std::time::{Duration, Instant};
+
+use super::guard::Run;
+
+/// Settings for benchmarking a solution.
+#[derive(Clone, Copy, Debug)]
+pub struct Bench {
+    /// The maximum amount of timed runs.
+    pub runs: usize,
+    /// Once this much time has been spent on timed runs, no further ones are started.
+    pub budget: Duration,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            runs: 100,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Bench {
+    /// Calls `run` once to warm up, and then repeatedly until either the amount of runs or the
+    /// time budget is exhausted; but at least once. Returns the warm-up run, and statistics over
+    /// the timed runs if the warm-up run was successful.
+    pub fn run(&self, mut run: impl FnMut() -> Run) -> (Run, Option<Stats>) {
+        let warm_up = run();
+        if warm_up.result.is_err() {
+            return (warm_up, None);
+        }
+
+        let mut times = vec![];
+        let start = Instant::now();
+        while times.is_empty() || (times.len() < self.runs && start.elapsed() < self.budget) {
+            let Run { result, time, .. } = run();
+            std::hint::black_box(result).ok();
+            times.push(time);
+        }
+
+        (warm_up, Some(Stats::from_times(times)))
+    }
+}
+
+/// Summary statistics over the run times of a benchmarked solution.
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// The sample standard deviation; zero if there was only one run.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Computes statistics over a non-empty list of run times.
+    fn from_times(mut times: Vec<Duration>) -> Self {
+        times.sort_unstable();
+
+        let runs = times.len();
+        let median = if runs.is_multiple_of(2) {
+            (times[runs / 2 - 1] + times[runs / 2]) / 2
+        } else {
+            times[runs / 2]
+        };
+
+        let mean = times.iter().map(Duration::as_secs_f64).sum::<f64>() / runs as f64;
+        let variance = times
+            .iter()
+            .map(|t| (t.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / (runs.max(2) - 1) as f64;
+
+        Self {
+            runs,
+            min: times[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(millis: &[u64]) -> Stats {
+        Stats::from_times(millis.iter().map(|&ms| Duration::from_millis(ms)).collect())
+    }
+
+    /// Rounds to whole microseconds, to hide floating point noise in the mean and deviation.
+    fn micros(time: Duration) -> u128 {
+        (time.as_nanos() + 500) / 1000
+    }
+
+    #[test]
+    fn median_of_odd_count() {
+        let stats = stats(&[30, 10, 20]);
+        assert_eq!(stats.runs, 3);
+        assert_eq!(stats.median, Duration::from_millis(20));
+    }
+
+    #[test]
+    fn median_of_even_count() {
+        let stats = stats(&[40, 10, 30, 20]);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.median, Duration::from_millis(25));
+    }
+
+    #[test]
+    fn min_and_mean() {
+        let stats = stats(&[50, 10, 30]);
+        assert_eq!(stats.min, Duration::from_millis(10));
+        assert_eq!(micros(stats.mean), 30_000);
+        // The sample standard deviation; the population one would be about 16.3ms.
+        assert_eq!(micros(stats.stddev), 20_000);
+    }
+
+    #[test]
+    fn single_run() {
+        let stats = stats(&[7]);
+        assert_eq!(stats.runs, 1);
+        assert_eq!(stats.min, Duration::from_millis(7));
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,288 @@
+//! The `fetch` and `submit` subcommands, which talk to the Advent of Code server: downloading
+//! puzzle inputs into `src/data`, and sending in answers.
+//!
+//! Both need the session token from the `session` cookie of a logged in browser. It's taken from
+//! the `AOC_SESSION` environment variable, or else from the `session` field of the config file
+//! (see [`CONFIG_FILE`]). The server to talk to can likewise be changed through `AOC_BASE_URL`
+//! or the `base_url` field, which is mostly useful for testing against a local stand-in.
+
+use std::{path::Path, time::Duration};
+
+use regex::Regex;
+
+use super::{
+    get_solution,
+    guard::run_guarded,
+    parse_part, parse_puzzle,
+    record::PARTS,
+    submissions::{Submissions, Verdict},
+    Error, Result, SOURCE_DIR,
+};
+
+/// The config file, holding a JSON object with the optional fields `session` and `base_url`.
+const CONFIG_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/.aoc/config.json");
+
+/// Where the verdicts for all submitted answers are kept; see [`Submissions`].
+const SUBMISSIONS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/.aoc/submissions.json");
+
+/// The server used if none is configured.
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent along with every request, as the Advent of Code maintainers ask automated tools to do.
+const USER_AGENT: &str = "github.com/Narvius (narvius@gmail.com)";
+
+/// Downloads the input for `day` of `year` (both as given on the command line) into the file
+/// `events!` expects it in. An input file that already has contents is left alone.
+pub fn fetch(year: &str, day: &str) -> Result<()> {
+    let (year, day) = parse_puzzle(year, day)?;
+    let path = Path::new(SOURCE_DIR).join(format!("data/aoc{year}/day{day:02}.txt"));
+    if std::fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+        return Err(Error::AlreadyExists(path).into());
+    }
+
+    let config = Config::load()?;
+    let input = config
+        .request(ureq::get(&format!(
+            "{}/{year}/day/{day}/input",
+            config.base_url
+        )))?
+        .call()?
+        .into_string()?;
+
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(&path, input)?;
+    println!("Saved the input for {year} day {day} to {}", path.display());
+    Ok(())
+}
+
+/// Submits an answer for `part` of `day` of `year` (all as given on the command line). If no
+/// `answer` is given, the solution is ran to get one, so it has to be compiled in. Answers that
+/// are known to be wrong from earlier submissions are refused without asking the server; and a
+/// right answer is also added to the year's answers file.
+pub fn submit(year: &str, day: &str, part: &str, answer: Option<&str>) -> Result<()> {
+    let (year, day) = parse_puzzle(year, day)?;
+    let part = parse_part("PART", Some(part.to_string()))?;
+    let answer = match answer {
+        Some(answer) => answer.to_string(),
+        None => {
+            let solution = get_solution(year, day).ok_or(Error::NoSolutions)?;
+            let f = [solution.0, solution.1][part];
+            run_guarded(f, solution.2, None).result?
+        }
+    };
+
+    let label = format!("{year}-{day:02}{}", PARTS[part]);
+    let mut submissions = Submissions::load(Path::new(SUBMISSIONS_FILE))?;
+    if let Some(reason) = submissions.check(year, day, part, &answer) {
+        println!("Not submitting {answer} for {label}: {reason}");
+        return Ok(());
+    }
+
+    let config = Config::load()?;
+    let url = format!("{}/{year}/day/{day}/answer", config.base_url);
+    let level = (part + 1).to_string();
+    let response = config
+        .request(ureq::post(&url))?
+        .send_form(&[("level", &level), ("answer", &answer)])?
+        .into_string()?;
+
+    let verdict = parse_verdict(&response)?;
+    println!("Submitted {answer} for {label}: {verdict}");
+
+    submissions.insert(year, day, part, &answer, verdict);
+    submissions.save(Path::new(SUBMISSIONS_FILE))?;
+    if verdict == Verdict::Right {
+        record_answer(year, day, part, &answer)?;
+    }
+    Ok(())
+}
+
+/// Settings for talking to the server.
+struct Config {
+    /// The value of the `session` cookie.
+    session: Option<String>,
+    /// The URL that all request paths are relative to, without a trailing slash.
+    base_url: String,
+}
+
+impl Config {
+    /// Loads the config from the environment and [`CONFIG_FILE`], in that order of priority.
+    fn load() -> Result<Self> {
+        let file: serde_json::Value = match std::fs::read_to_string(CONFIG_FILE) {
+            Ok(text) => serde_json::from_str(&text)?,
+            Err(_) => serde_json::Value::Null,
+        };
+        let setting = |var: &str, field: &str| {
+            std::env::var(var)
+                .ok()
+                .or_else(|| file[field].as_str().map(String::from))
+        };
+
+        Ok(Self {
+            session: setting("AOC_SESSION", "session"),
+            base_url: setting("AOC_BASE_URL", "base_url")
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+        })
+    }
+
+    /// Adds the headers every request needs to `request`.
+    fn request(&self, request: ureq::Request) -> Result<ureq::Request> {
+        let session = self.session.as_ref().ok_or(Error::MissingSession)?;
+        Ok(request
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT))
+    }
+}
+
+/// Figures out the verdict from the HTML page the server sends back after a submission.
+fn parse_verdict(response: &str) -> Result<Verdict> {
+    // Only the main text of the page is of interest, without any markup.
+    let text = response
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(response, |(article, _)| article);
+    let text = Regex::new(r"<[^>]*>")?.replace_all(text, "");
+
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Right
+    } else if text.contains("You gave an answer too recently") {
+        // Like "You have 1m 30s left to wait" or "You have 42s left to wait".
+        let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")?;
+        let seconds = wait.captures(&text).map_or(60, |captures| {
+            let number = |i| {
+                captures
+                    .get(i)
+                    .map_or(0, |m| m.as_str().parse().unwrap_or(0))
+            };
+            60 * number(1) + number(2)
+        });
+        Verdict::Wait(Duration::from_secs(seconds))
+    } else if text.contains("Did you already complete it") {
+        Verdict::AlreadySolved
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else {
+        return Err(Error::UnexpectedResponse(text.trim().to_string()).into());
+    };
+
+    Ok(verdict)
+}
+
+/// Adds a right `answer` to the year's answers file, so that `--check` and the answer tests know
+/// about it. The second part is only added if the first is already there.
+fn record_answer(year: usize, day: usize, part: usize, answer: &str) -> Result<()> {
+    let path = Path::new(SOURCE_DIR).join(format!("data/aoc{year}/answers.json"));
+    let text = std::fs::read_to_string(&path).ok();
+    match add_answer(text.as_deref(), day, part, answer)? {
+        Some(text) => {
+            std::fs::write(&path, text)?;
+            println!("Added the answer to {}", path.display());
+        }
+        None => println!("Not adding the answer to {}", path.display()),
+    }
+    Ok(())
+}
+
+/// Adds `answer` for the given part to `text`, the contents of an answers file if there is one.
+/// Returns the new contents, or `None` if the answer doesn't go right after the known ones.
+fn add_answer(text: Option<&str>, day: usize, part: usize, answer: &str) -> Result<Option<String>> {
+    let mut answers: serde_json::Map<String, serde_json::Value> = match text {
+        Some(text) => serde_json::from_str(text)?,
+        None => Default::default(),
+    };
+
+    let entry = answers
+        .entry(format!("day{day:02}"))
+        .or_insert_with(|| serde_json::json!([]));
+    let Some(parts) = entry.as_array_mut().filter(|parts| parts.len() == part) else {
+        return Ok(None);
+    };
+    parts.push(answer.into());
+
+    // Written by hand to keep the file's layout of one line per day.
+    let lines: Vec<_> = answers
+        .iter()
+        .map(|(day, parts)| {
+            let parts = parts.as_array().into_iter().flatten();
+            let parts: Vec<_> = parts.map(|part| part.to_string()).collect();
+            format!("  \"{day}\": [{}]", parts.join(", "))
+        })
+        .collect();
+    Ok(Some(format!("{{\n{}\n}}\n", lines.join(",\n"))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verdict(text: &str) -> Verdict {
+        let page = format!("<html><main><article><p>{text}</p></article></main></html>");
+        parse_verdict(&page).unwrap()
+    }
+
+    #[test]
+    fn right_answer() {
+        let text = "That's the right answer! You are <em>one gold star</em> closer.";
+        assert_eq!(verdict(text), Verdict::Right);
+    }
+
+    #[test]
+    fn wrong_answers() {
+        let wrong = "That's not the right answer; your answer is too high. Please wait one minute.";
+        assert_eq!(verdict(wrong), Verdict::TooHigh);
+        let wrong = "That's not the right answer; your answer is too low. Please wait one minute.";
+        assert_eq!(verdict(wrong), Verdict::TooLow);
+        let wrong =
+            "That's not the right answer. If you're stuck, make sure you're using the full \
+            input data.";
+        assert_eq!(verdict(wrong), Verdict::Wrong);
+    }
+
+    #[test]
+    fn waiting() {
+        let wait = "You gave an answer too recently. You have 1m 30s left to wait.";
+        assert_eq!(verdict(wait), Verdict::Wait(Duration::from_secs(90)));
+        let wait = "You gave an answer too recently. You have 42s left to wait.";
+        assert_eq!(verdict(wait), Verdict::Wait(Duration::from_secs(42)));
+    }
+
+    #[test]
+    fn already_solved() {
+        let text = "You don't seem to be solving the right level. Did you already complete it?";
+        assert_eq!(verdict(text), Verdict::AlreadySolved);
+    }
+
+    #[test]
+    fn unexpected_page() {
+        let why = parse_verdict("<article><p>Something else</p></article>").unwrap_err();
+        assert_eq!(why.to_string(), "unexpected response: Something else");
+    }
+
+    #[test]
+    fn answers_are_added_in_order() {
+        let text = "{\n  \"day01\": [\"11\", \"31\"],\n  \"day02\": [\"2\"]\n}\n";
+        let added = add_answer(Some(text), 2, 1, "4").unwrap();
+        let expected = "{\n  \"day01\": [\"11\", \"31\"],\n  \"day02\": [\"2\", \"4\"]\n}\n";
+        assert_eq!(added.as_deref(), Some(expected));
+
+        let added = add_answer(Some(text), 3, 0, "7").unwrap().unwrap();
+        assert!(added.ends_with("  \"day02\": [\"2\"],\n  \"day03\": [\"7\"]\n}\n"));
+
+        assert_eq!(add_answer(Some(text), 3, 1, "7").unwrap(), None);
+        assert_eq!(add_answer(Some(text), 1, 1, "31").unwrap(), None);
+    }
+
+    #[test]
+    fn answers_file_is_created() {
+        let added = add_answer(None, 1, 0, "11").unwrap();
+        assert_eq!(added.as_deref(), Some("{\n  \"day01\": [\"11\"]\n}\n"));
+    }
+}
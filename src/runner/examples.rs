@@ -0,0 +1,138 @@
+//! Puzzle examples as test fixtures. They are stored next to the real input, in
+//! `src/data/aocYYYY/dayXX_examples.txt`, and ran by the tests generated in [`events`].
+//!
+//! An examples file consists of sections, each starting with a header line like `=== one: 142`,
+//! which names the part (`one` or `two`) and the expected answer. Everything up to the next header
+//! is the example input. A part can have any number of examples, in any order.
+
+use super::{get_solution, Result, SOURCE_DIR};
+
+/// A single example, as parsed from an examples file.
+struct Example {
+    /// The line of the header in the examples file, for error messages.
+    line: usize,
+    /// The part (0 or 1) the example is for.
+    part: usize,
+    /// The expected answer.
+    expected: String,
+    /// The example input.
+    input: String,
+}
+
+/// Runs the solution for `day` of `year` against all of its examples, and panics with a list of
+/// all wrong answers and errors if there are any. `module` and `name` are the names used in
+/// [`events`], and determine where the examples file is. A missing file means there's nothing to
+/// check.
+pub fn check(year: usize, module: &str, name: &str) {
+    let path = format!("{SOURCE_DIR}/data/{module}/{name}_examples.txt");
+    let Ok(text) = std::fs::read_to_string(&path) else {
+        return;
+    };
+    let examples = parse(&text).unwrap_or_else(|why| panic!("{path}: {why}"));
+
+    let day = name[3..].parse().expect("day modules are called dayXX");
+    let solution = get_solution(year, day).expect("the test exists, so the solution does");
+
+    let mut failures = vec![];
+    for example in examples {
+        let solve = [solution.0, solution.1][example.part];
+        match solve(&example.input) {
+            Ok(answer) if answer == example.expected => {}
+            Ok(answer) => failures.push(format!(
+                "line {}: expected {}, got {answer}",
+                example.line, example.expected
+            )),
+            Err(why) => failures.push(format!("line {}: failed: {why}", example.line)),
+        }
+    }
+
+    assert!(failures.is_empty(), "{path}\n{}", failures.join("\n"));
+}
+
+/// Parses the contents of an examples file.
+fn parse(text: &str) -> Result<Vec<Example>> {
+    let mut examples: Vec<Example> = vec![];
+    for (i, line) in text.lines().enumerate() {
+        let malformed = || format!("line {}: expected a header like `=== one: 142`", i + 1);
+
+        let Some(header) = line.strip_prefix("=== ") else {
+            let example = examples.last_mut().ok_or_else(malformed)?;
+            example.input.push_str(line);
+            example.input.push('\n');
+            continue;
+        };
+
+        let answer = header
+            .split_once(": ")
+            .filter(|(_, expected)| !expected.trim().is_empty());
+        let (part, expected) = match answer {
+            Some(("one", expected)) => (0, expected),
+            Some(("two", expected)) => (1, expected),
+            _ => return Err(malformed().into()),
+        };
+        examples.push(Example {
+            line: i + 1,
+            part,
+            expected: expected.to_string(),
+            input: String::new(),
+        });
+    }
+
+    // Blank lines in between sections are just for readability.
+    for example in &mut examples {
+        example
+            .input
+            .truncate(example.input.trim_end_matches('\n').len());
+        example.input.push('\n');
+    }
+
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(text: &str) -> String {
+        parse(text).err().expect("expected an error").to_string()
+    }
+
+    #[test]
+    fn parses_examples() {
+        let text = "=== one: 142\n1abc2\ntreb7uchet\n\n=== two: 281\ntwo1nine\n\n\n=== one: 0\n";
+        let examples = parse(text).unwrap();
+        let summary: Vec<_> = examples
+            .iter()
+            .map(|e| (e.line, e.part, e.expected.as_str(), e.input.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (1, 0, "142", "1abc2\ntreb7uchet\n"),
+                (5, 1, "281", "two1nine\n"),
+                (9, 0, "0", "\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_answer() {
+        let expected = "line 1: expected a header like `=== one: 142`";
+        assert_eq!(error("=== one\n1abc2\n"), expected);
+        assert_eq!(error("=== one: \n1abc2\n"), expected);
+    }
+
+    #[test]
+    fn bad_part() {
+        let text = "=== one: 142\n1abc2\n=== three: 7\nxyz\n";
+        assert_eq!(error(text), "line 3: expected a header like `=== one: 142`");
+    }
+
+    #[test]
+    fn input_before_header() {
+        assert_eq!(
+            error("1abc2\n=== one: 142\n"),
+            "line 1: expected a header like `=== one: 142`"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! Regression tests against the known answers, ran by the tests generated in [`events`]. These
+//! make sure that changes to shared code (like `common`) don't break any existing solutions.
+
+use super::{get_answer, get_solution, STACK_SIZE};
+
+/// Runs `part` (0 or 1) of the solution for `day` of `year` on its embedded input, and panics if
+/// the result differs from the answer recorded in the year's answers file. `name` is the module
+/// name used in [`events`]. Parts without a recorded answer have nothing to check.
+pub fn check(year: usize, name: &str, part: usize) {
+    let day = name[3..].parse().expect("day modules are called dayXX");
+    let Some(expected) = get_answer(year, day, part) else {
+        return;
+    };
+    let solution = get_solution(year, day).expect("the test exists, so the solution does");
+    let solve = [solution.0, solution.1][part];
+
+    // Test threads have a small stack by default, which isn't enough for some solutions.
+    let result = std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || solve(solution.2).map_err(|why| why.to_string()))
+        .expect("failed to spawn thread")
+        .join()
+        .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+
+    match result {
+        Ok(answer) => assert_eq!(answer, expected, "wrong answer"),
+        Err(why) => panic!("failed: {why}"),
+    }
+}
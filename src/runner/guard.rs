@@ -0,0 +1,105 @@
+//! Isolation of solutions from the runner, so that a panicking or runaway solution doesn't take
+//! down an entire sweep.
+
+use std::{
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Once},
+    time::{Duration, Instant},
+};
+
+use super::{
+    memory::{self, Memory},
+    STACK_SIZE,
+};
+use crate::{Error, Result, SolutionFn};
+
+thread_local! {
+    /// Whether the current thread is running a solution through [`run_guarded`]; panics on such
+    /// threads are reported as failures instead of being printed.
+    static GUARDED: Cell<bool> = const { Cell::new(false) };
+
+    /// The message of the last panic caught on this thread, including its location.
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// The result of a single run of a solution.
+pub struct Run {
+    pub result: Result<String>,
+    pub time: Duration,
+    /// The memory used by the run, if measured; see [`memory::measure`].
+    pub memory: Option<Memory>,
+}
+
+/// Runs `f` on `input`, and times it and measures its memory usage. A panic inside `f` is turned
+/// into an [`Error::Panicked`]. If a `timeout` is given, `f` runs on a separate thread, which is
+/// abandoned with an [`Error::Timeout`] if it doesn't finish in time.
+pub fn run_guarded(f: SolutionFn, input: &str, timeout: Option<Duration>) -> Run {
+    install_panic_hook();
+
+    let Some(timeout) = timeout else {
+        return catch_panic(f, input);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+    let spawned = std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let _ = sender.send(catch_panic(f, &input));
+        });
+
+    let failed = |error, time| Run {
+        result: Err(error),
+        time,
+        memory: None,
+    };
+    if let Err(why) = spawned {
+        return failed(Error::Other(why.to_string()), Duration::ZERO);
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(run) => run,
+        Err(mpsc::RecvTimeoutError::Timeout) => failed(Error::Timeout(timeout), timeout),
+        Err(mpsc::RecvTimeoutError::Disconnected) => failed(
+            Error::Other("worker thread vanished".to_string()),
+            Duration::ZERO,
+        ),
+    }
+}
+
+/// Runs `f` on `input` on the current thread, converting a panic into an error.
+fn catch_panic(f: SolutionFn, input: &str) -> Run {
+    GUARDED.set(true);
+    let start = Instant::now();
+    let (result, memory) = memory::measure(|| panic::catch_unwind(AssertUnwindSafe(|| f(input))));
+    let time = start.elapsed();
+    GUARDED.set(false);
+
+    let result = result.unwrap_or_else(|_| {
+        let message = PANIC_MESSAGE.take();
+        let message = message.as_deref().unwrap_or("panicked");
+        Err(Error::Panicked(message.replace('\n', " ")))
+    });
+    Run {
+        result,
+        time,
+        memory,
+    }
+}
+
+/// Replaces the panic hook with one that stores the message for panics in guarded solutions, and
+/// otherwise defers to the default hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if GUARDED.get() {
+                PANIC_MESSAGE.set(Some(info.to_string()));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
@@ -0,0 +1,65 @@
+//! Running many solution parts in a row, optionally spread across several threads.
+
+use std::{
+    collections::BTreeMap,
+    sync::{atomic::AtomicUsize, atomic::Ordering, mpsc},
+};
+
+use super::{eval_single, record::Record, Options, Result, STACK_SIZE};
+
+/// Runs each (year, day, part) in `tasks`, using up to `jobs` worker threads, and passes every
+/// resulting [`Record`] to `f`. Records are passed on in the order of `tasks`, no matter in which
+/// order the solutions finish.
+pub fn run_in_order(
+    tasks: &[(usize, usize, usize)],
+    jobs: usize,
+    options: &Options,
+    mut f: impl FnMut(Record),
+) -> Result<()> {
+    if jobs <= 1 {
+        for &(year, day, part) in tasks {
+            if let Some(record) = eval_single(year, day, part, options) {
+                f(record);
+            }
+        }
+        return Ok(());
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.min(tasks.len()) {
+            let (next, sender) = (&next, sender.clone());
+            std::thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, move || {
+                    let mut index = next.fetch_add(1, Ordering::Relaxed);
+                    while let Some(&(year, day, part)) = tasks.get(index) {
+                        let record = eval_single(year, day, part, options);
+                        if sender.send((index, record)).is_err() {
+                            break;
+                        }
+                        index = next.fetch_add(1, Ordering::Relaxed);
+                    }
+                })?;
+        }
+        drop(sender);
+
+        // Results arrive in the order the solutions finish; hold on to them until all the ones
+        // that come before have arrived.
+        let mut pending = BTreeMap::new();
+        let mut done = 0;
+        for (index, record) in receiver {
+            pending.insert(index, record);
+            while let Some(record) = pending.remove(&done) {
+                done += 1;
+                if let Some(record) = record {
+                    f(record);
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
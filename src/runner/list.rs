@@ -0,0 +1,108 @@
+//! The `list` subcommand, which shows which solutions exist and how far along they are.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+use super::{
+    solution_path,
+    source::{self, Status},
+    Result, SOURCE_DIR,
+};
+use crate::CONTENTS;
+
+/// Prints a year×day matrix of all solutions, followed by lists of anything that looks off:
+/// registered solutions without input data, and solution files that aren't registered.
+pub fn print() -> Result<()> {
+    let root = Path::new(SOURCE_DIR);
+    let on_disk = root.join("solutions").is_dir();
+
+    let mut years: BTreeSet<_> = CONTENTS.iter().map(|v| v.0).collect();
+    if on_disk {
+        for entry in std::fs::read_dir(root.join("solutions"))? {
+            let name = entry?.file_name();
+            let year = name.to_str().and_then(|name| name.strip_prefix("aoc"));
+            years.extend(year.and_then(|year| year.parse::<usize>().ok()));
+        }
+    }
+
+    let (mut unregistered, mut no_data) = (BTreeMap::<_, Vec<_>>::new(), vec![]);
+
+    print!("      ");
+    for day in 1..=25 {
+        print!(" {day:>2}");
+    }
+    println!();
+
+    for &year in &years {
+        print!("{year}  ");
+        for day in 1..=25 {
+            let cell = match super::get_solution(year, day) {
+                Some(&(_, _, input, source)) => {
+                    if input.trim().is_empty() {
+                        no_data.push(format!("{year}-{day:02}"));
+                    }
+                    [source::status(source, "one"), source::status(source, "two")].map(symbol)
+                }
+                None if on_disk && solution_path(root, year, day).is_file() => {
+                    unregistered.entry(year).or_default().push(day);
+                    ['~', '~']
+                }
+                None => ['.', '.'],
+            };
+            print!(" {}{}", cell[0], cell[1]);
+        }
+        println!();
+    }
+
+    println!();
+    println!("## solved   -- unimplemented   ** freebie   ~~ not registered   .. missing");
+
+    if !no_data.is_empty() {
+        println!(
+            "\nRegistered, but with empty input data: {}",
+            no_data.join(", ")
+        );
+    }
+
+    if !on_disk {
+        println!("\nSource directory {SOURCE_DIR} not found; can't check for unregistered files.");
+    } else if !unregistered.is_empty() {
+        println!("\nOn disk, but not registered in `events!` or not enabled by a feature:");
+        for (year, days) in unregistered {
+            println!("    {year}: {}", format_days(&days));
+        }
+    }
+
+    Ok(())
+}
+
+/// The symbol used for a part with the given [`Status`] in the matrix.
+fn symbol(status: Status) -> char {
+    match status {
+        Status::Solved => '#',
+        Status::Unimplemented => '-',
+        Status::Freebie => '*',
+    }
+}
+
+/// Formats a sorted list of days compactly, merging consecutive days into ranges.
+fn format_days(days: &[usize]) -> String {
+    let mut ranges: Vec<(usize, usize)> = vec![];
+    for &day in days {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == day => *end = day,
+            _ => ranges.push((day, day)),
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| match start == end {
+            true => format!("{start}"),
+            false => format!("{start}-{end}"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,130 @@
+//! Measuring the memory usage of individual solutions, with the `peak_alloc` feature.
+//!
+//! This is done by an allocator that counts every allocation on the thread doing it. As each
+//! solution runs on a single thread, the counts for a solution are unaffected by others running
+//! in parallel (see [`jobs`](super::jobs)).
+
+/// The memory usage of a single run of a solution.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Memory {
+    /// The most bytes that were allocated at the same time.
+    pub peak: usize,
+    /// The sum of the sizes of all allocations.
+    pub total: usize,
+    /// How many allocations were made.
+    pub allocations: usize,
+}
+
+/// Calls `f`, and measures the memory it allocates on the current thread. Without the
+/// `peak_alloc` feature, nothing is measured.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Memory>) {
+    #[cfg(feature = "peak_alloc")]
+    {
+        tracking::reset();
+        let result = f();
+        (result, Some(tracking::read()))
+    }
+
+    #[cfg(not(feature = "peak_alloc"))]
+    (f(), None)
+}
+
+/// Formats an amount of bytes for output, in a fitting unit.
+pub fn format_bytes(bytes: usize) -> String {
+    let bytes = bytes as f64;
+    if bytes < 1024.0 {
+        format!("{bytes}b")
+    } else if bytes < 1024.0 * 1024.0 {
+        format!("{:.1}kb", bytes / 1024.0)
+    } else {
+        format!("{:.1}mb", bytes / (1024.0 * 1024.0))
+    }
+}
+
+#[cfg(feature = "peak_alloc")]
+mod tracking {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use super::Memory;
+
+    /// The system allocator, with every allocation counted for the current thread.
+    struct TrackingAlloc;
+
+    #[global_allocator]
+    static ALLOCATOR: TrackingAlloc = TrackingAlloc;
+
+    thread_local! {
+        /// The bytes currently allocated on this thread, since the last [`reset`]. Memory
+        /// allocated before that and freed after counts negatively.
+        static CURRENT: Cell<isize> = const { Cell::new(0) };
+        static PEAK: Cell<usize> = const { Cell::new(0) };
+        static TOTAL: Cell<usize> = const { Cell::new(0) };
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Resets all counters of the current thread.
+    pub fn reset() {
+        CURRENT.set(0);
+        PEAK.set(0);
+        TOTAL.set(0);
+        ALLOCATIONS.set(0);
+    }
+
+    /// Reads the counters of the current thread.
+    pub fn read() -> Memory {
+        Memory {
+            peak: PEAK.get(),
+            total: TOTAL.get(),
+            allocations: ALLOCATIONS.get(),
+        }
+    }
+
+    /// Records that an allocation changed the amount of allocated memory by `change` bytes, and
+    /// requested `size` bytes. `size` is 0 for deallocations.
+    fn record(change: isize, size: usize) {
+        // Thread locals may already be gone while a thread shuts down; those allocations are of
+        // no interest anyway.
+        let _ = CURRENT.try_with(|current| {
+            current.set(current.get() + change);
+            PEAK.with(|peak| peak.set(peak.get().max(current.get().max(0) as usize)));
+            if size > 0 {
+                TOTAL.with(|total| total.set(total.get() + size));
+                ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+            }
+        });
+    }
+
+    unsafe impl GlobalAlloc for TrackingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(layout.size() as isize, layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(layout.size() as isize, layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(-(layout.size() as isize), 0);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record(new_size as isize - layout.size() as isize, new_size);
+            }
+            new_ptr
+        }
+    }
+}
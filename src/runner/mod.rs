@@ -0,0 +1,845 @@
+//! Contains all code that constitutes the harness for running solutions from the command line.
+
+mod baseline;
+mod bench;
+#[cfg(feature = "client")]
+mod client;
+#[cfg(test)]
+pub mod examples;
+#[cfg(test)]
+pub mod golden;
+mod guard;
+mod jobs;
+mod list;
+mod memory;
+mod record;
+mod repl;
+mod report;
+mod scaffold;
+mod source;
+#[cfg(feature = "client")]
+mod submissions;
+mod watch;
+
+use std::{
+    collections::BTreeMap,
+    io::Read,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    registry::{get_answer, get_solution, valid_input},
+    ASSUMED_YEAR, CONTENTS,
+};
+use baseline::Baseline;
+use bench::Bench;
+use guard::run_guarded;
+use record::{format_duration, Outcome, Record};
+use report::Report;
+
+/// The result type used by the runner itself. Unlike solutions, it deals with all sorts of errors
+/// besides [`crate::Error`], like failed file accesses.
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Parses command line arguments and runs the corresponding solutions, printing the results
+/// back to standard output. If an error occurs, returns it.
+pub fn run_from_cmd_args() -> Result<()> {
+    let (mut args, mut options) = Options::parse(std::env::args().skip(1))?;
+
+    // Subcommands, which do something other than running solutions.
+    match args.first().map(String::as_str) {
+        Some("list") if args.len() == 1 => return list::print(),
+        Some("list") => return Err(Error::ExtraArgs("list").into()),
+        Some("new") if args.len() == 3 => return scaffold::create(&args[1], &args[2]),
+        Some("new") => return Err(Error::Usage("new YEAR DAY").into()),
+        Some("watch") => return watch::run(&args[1..]),
+        Some("repl") if args.len() == 3 => return repl::run(&args[1], &args[2], &options),
+        Some("repl") => return Err(Error::Usage("repl YEAR DAY").into()),
+        #[cfg(feature = "client")]
+        Some("fetch") if args.len() == 3 => return client::fetch(&args[1], &args[2]),
+        #[cfg(feature = "client")]
+        Some("submit") if (4..=5).contains(&args.len()) => {
+            let answer = args.get(4).map(String::as_str);
+            return client::submit(&args[1], &args[2], &args[3], answer);
+        }
+        #[cfg(not(feature = "client"))]
+        Some(command @ ("fetch" | "submit")) => {
+            return Err(Error::MissingFeature(command.to_string(), "client").into())
+        }
+        #[cfg(feature = "client")]
+        Some("fetch") => return Err(Error::Usage("fetch YEAR DAY").into()),
+        #[cfg(feature = "client")]
+        Some("submit") => return Err(Error::Usage("submit YEAR DAY PART [ANSWER]").into()),
+        // Runs solutions like usual, just with a different output.
+        Some("report") => {
+            args.remove(0);
+            options.report.get_or_insert_with(Report::default);
+        }
+        _ => {}
+    }
+
+    options.take_part_suffix(&mut args)?;
+
+    use Input::*;
+    let (years, days): (Vec<_>, Vec<_>) = match args.len() {
+        0 => {
+            // The highest day with a solution.
+            let day = (1..=25)
+                .rev()
+                .find(|&day| get_solution(ASSUMED_YEAR, day).is_some());
+            (vec![ASSUMED_YEAR], vec![day.ok_or(Error::NoSolutions)?])
+        }
+        1 => {
+            // Days to run, or . to run all days.
+            match Input::from_day(args[0].as_ref())? {
+                All => (vec![ASSUMED_YEAR], (1..=25).collect()),
+                Specific(days) => (vec![ASSUMED_YEAR], days),
+            }
+        }
+        2 => {
+            // [year, day]. Year can be given either as 2015 or 15 (if it's less than 100, 2000
+            // is implicitly added to it). Both year and day can still be '.' for "all", and
+            // either can be a range or list.
+            let year = Input::from_year(args[0].as_ref())?;
+            let day = Input::from_day(args[1].as_ref())?;
+
+            let years = match year {
+                All => CONTENTS.iter().map(|v| v.0).collect(),
+                Specific(years) => years,
+            };
+            let days = match day {
+                All => (1..=25).collect(),
+                Specific(days) => days,
+            };
+            (years, days)
+        }
+        _ => return Err(Error::WrongArgCount(args.len()).into()),
+    };
+
+    // An input override only makes sense for a single puzzle, and can't be checked against the
+    // known answers for the embedded input.
+    if options.input.is_some() && (years.len() > 1 || days.len() > 1) {
+        return Err(Error::AmbiguousInput.into());
+    }
+    if options.input.is_some() && options.check {
+        return Err(Error::IncompatibleFlags("--input", "--check").into());
+    }
+    if options.input.is_some() && options.save_baseline.is_some() {
+        return Err(Error::IncompatibleFlags("--input", "--save-baseline").into());
+    }
+    if options.json && options.report.is_some() {
+        return Err(Error::IncompatibleFlags("report", "--json").into());
+    }
+
+    eval(years, days, &options)
+}
+
+/// Options given as flags on the command line, which modify how the selected solutions are ran.
+#[derive(Default)]
+struct Options {
+    /// If present, the solution is ran on this input instead of the one embedded in the binary.
+    input: Option<String>,
+    /// Whether to compare results against the known answers.
+    check: bool,
+    /// Whether to print results as JSON records instead of human-readable lines.
+    json: bool,
+    /// If present, each solution is ran repeatedly and statistics over the run times are shown.
+    bench: Option<Bench>,
+    /// If present, run times are compared against the baseline stored in this file.
+    baseline: Option<PathBuf>,
+    /// If present, run times are stored in this baseline file.
+    save_baseline: Option<PathBuf>,
+    /// How many percent slower than the baseline a solution may get before it counts as a
+    /// regression; [`DEFAULT_THRESHOLD`] if not given.
+    threshold: Option<f64>,
+    /// If present, solutions are ran on this many threads in parallel.
+    jobs: Option<usize>,
+    /// If present, solutions taking longer than this are abandoned and count as failed.
+    timeout: Option<Duration>,
+    /// If present, only this part (0 or 1) of each solution is ran.
+    part: Option<usize>,
+    /// If present, the results are written as a report instead of being printed one by one.
+    report: Option<Report>,
+}
+
+/// The `src` directory of this crate, as it was during compilation. Used for features that need to
+/// look at or change the source files themselves.
+const SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The path of the solution file for `year` and `day` within the source directory `root`.
+fn solution_path(root: &Path, year: usize, day: usize) -> PathBuf {
+    root.join(format!("solutions/aoc{year}/day{day:02}.rs"))
+}
+
+/// The stack size for threads that run solutions. Some solutions recurse fairly deeply, so this
+/// is generous compared to the default for spawned threads.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// The default for [`Options::threshold`].
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+impl Options {
+    /// Separates all flags from the command line arguments, and parses them into an [`Options`]
+    /// value. Returns the remaining positional arguments alongside it.
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<(Vec<String>, Options)> {
+        let (mut positional, mut options) = (vec![], Options::default());
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-i" | "--input" => {
+                    let path = args.next().ok_or(Error::MissingValue(arg))?;
+                    options.input = Some(read_input(&path)?);
+                }
+                "-c" | "--check" => options.check = true,
+                "--json" => options.json = true,
+                "-b" | "--bench" => {
+                    options.bench.get_or_insert_with(Bench::default);
+                }
+                "--runs" => {
+                    let runs = parse_value(&arg, args.next())?;
+                    options.bench.get_or_insert_with(Bench::default).runs = runs;
+                }
+                "--budget" => {
+                    let budget = parse_seconds(&arg, args.next())?;
+                    options.bench.get_or_insert_with(Bench::default).budget = budget;
+                }
+                "--baseline" => options.baseline = Some(parse_value(&arg, args.next())?),
+                "--save-baseline" => {
+                    options.save_baseline = Some(parse_value(&arg, args.next())?);
+                }
+                "--threshold" => options.threshold = Some(parse_value(&arg, args.next())?),
+                "-j" | "--jobs" => {
+                    // 0 means "as many as there are cores".
+                    let jobs = match parse_value(&arg, args.next())? {
+                        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+                        jobs => jobs,
+                    };
+                    options.jobs = Some(jobs);
+                }
+                "-t" | "--timeout" => options.timeout = Some(parse_seconds(&arg, args.next())?),
+                "-p" | "--part" => options.part = Some(parse_part(&arg, args.next())?),
+                "-o" | "--output" => {
+                    let output = parse_value(&arg, args.next())?;
+                    options.report.get_or_insert_with(Report::default).output = Some(output);
+                }
+                "--html" => {
+                    let html = parse_value(&arg, args.next())?;
+                    options.report.get_or_insert_with(Report::default).html = Some(html);
+                }
+                _ if arg.starts_with("--") => return Err(Error::UnknownFlag(arg).into()),
+                _ => positional.push(arg),
+            }
+        }
+
+        Ok((positional, options))
+    }
+
+    /// Strips a part suffix off of the day argument (the last one), which acts like `--part`.
+    /// Fails if both select a part, and they disagree.
+    fn take_part_suffix(&mut self, args: &mut [String]) -> std::result::Result<(), Error> {
+        if let Some(day) = args.last_mut() {
+            if let (rest, Some(part)) = split_part(day) {
+                if let Some(flag) = self.part.filter(|&flag| flag != part) {
+                    return Err(Error::ConflictingParts(part, flag));
+                }
+                *day = rest.to_string();
+                self.part = Some(part);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the value given after the flag `flag`.
+fn parse_value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T> {
+    let value = value.ok_or_else(|| Error::MissingValue(flag.to_string()))?;
+    value
+        .parse()
+        .map_err(|_| Error::InvalidValue(flag.to_string(), value).into())
+}
+
+/// Parses the value given after the flag `flag` as a (possibly fractional) number of seconds.
+fn parse_seconds(flag: &str, value: Option<String>) -> Result<Duration> {
+    let seconds = parse_value(flag, value.clone())?;
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| Error::InvalidValue(flag.to_string(), value.unwrap_or_default()).into())
+}
+
+/// Reads a puzzle input from the file at `path`, or from standard input if `path` is `-`.
+fn read_input(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        Ok(std::fs::read_to_string(path)?)
+    }
+}
+
+/// Possible command line inputs.
+enum Input {
+    /// Corresponds to the user supplying numbers: a single one, an inclusive range like `1-10`
+    /// or `2016..2018`, or a comma-separated list of those.
+    Specific(Vec<usize>),
+    /// Corresponds to the user supplying `.` as an argument.
+    All,
+}
+
+impl Input {
+    /// Parses a command line argument for day into an [`Input`] value.
+    fn from_day(arg: &str) -> Result<Input> {
+        Self::parse(arg, |day, _| {
+            if (1..=25).contains(&day) {
+                Ok(Some(day))
+            } else {
+                Err(Error::OutOfRange(day, 1, 25))
+            }
+        })
+    }
+
+    /// Parses a command line argument for year into an [`Input`] value. Also accepts values from
+    /// 15 and up, in addition to the actual expected range of 2015 and up. Years without
+    /// solutions are an error when given on their own, and skipped when part of a range.
+    fn from_year(arg: &str) -> Result<Input> {
+        Self::parse(arg, |mut year, in_range| {
+            if year < 100 {
+                year += 2000;
+            }
+
+            if CONTENTS.iter().any(|v| v.0 == year) {
+                Ok(Some(year))
+            } else if in_range {
+                Ok(None)
+            } else {
+                Err(Error::InvalidYear(
+                    year,
+                    CONTENTS.iter().map(|v| v.0).collect(),
+                ))
+            }
+        })
+    }
+
+    /// Parses either `.` or a list of numbers and ranges. Every number is passed to `check`
+    /// alongside whether it is part of a range; `check` returns the (possibly adjusted) number,
+    /// `None` to skip it, or an error if it is invalid.
+    fn parse(
+        arg: &str,
+        check: impl Fn(usize, bool) -> std::result::Result<Option<usize>, Error>,
+    ) -> Result<Input> {
+        if arg == "." {
+            return Ok(Input::All);
+        }
+
+        let number = |s: &str| {
+            s.parse::<usize>()
+                .map_err(|_| Error::InvalidArg(arg.to_string()))
+        };
+
+        let mut values = vec![];
+        for item in arg.split(',') {
+            match item.split_once("..").or_else(|| item.split_once('-')) {
+                Some((low, high)) => {
+                    let (low, high) = (number(low)?, number(high)?);
+                    if low > high {
+                        return Err(Error::InvalidArg(arg.to_string()).into());
+                    }
+                    for value in low..=high {
+                        values.extend(check(value, true)?);
+                    }
+                }
+                None => values.extend(check(number(item)?, false)?),
+            }
+        }
+
+        values.sort_unstable();
+        values.dedup();
+        Ok(Input::Specific(values))
+    }
+}
+
+/// Parses a single year and day, as given to subcommands that work on one specific puzzle, which
+/// doesn't need to have a solution yet. The year can be given in full or as just two digits.
+fn parse_puzzle(year: &str, day: &str) -> Result<(usize, usize)> {
+    let year = match year.parse::<usize>() {
+        Ok(year) if year < 100 => year + 2000,
+        Ok(year) => year,
+        Err(_) => return Err(Error::InvalidArg(year.to_string()).into()),
+    };
+    if !(2015..=2099).contains(&year) {
+        return Err(Error::OutOfRange(year, 2015, 2099).into());
+    }
+    let day = day
+        .parse::<usize>()
+        .map_err(|_| Error::InvalidArg(day.to_string()))?;
+    if !(1..=25).contains(&day) {
+        return Err(Error::OutOfRange(day, 1, 25).into());
+    }
+    Ok((year, day))
+}
+
+/// Splits a part suffix (`a` or `b`) off of a day argument like `17b`, if there is one.
+fn split_part(arg: &str) -> (&str, Option<usize>) {
+    match arg.strip_suffix(['a', 'b']) {
+        Some(rest) => (rest, Some(usize::from(arg.ends_with('b')))),
+        None => (arg, None),
+    }
+}
+
+/// Parses a part given as `a`/`b`, `1`/`2` or `one`/`two` into 0 or 1.
+fn parse_part(flag: &str, value: Option<String>) -> Result<usize> {
+    let value = value.ok_or_else(|| Error::MissingValue(flag.to_string()))?;
+    match value.as_str() {
+        "a" | "1" | "one" => Ok(0),
+        "b" | "2" | "two" => Ok(1),
+        _ => Err(Error::InvalidValue(flag.to_string(), value).into()),
+    }
+}
+
+/// Error type for this program.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Error {
+    WrongArgCount(usize),
+    ExtraArgs(&'static str),
+    Usage(&'static str),
+    InvalidYear(usize, Vec<usize>),
+    OutOfRange(usize, usize, usize),
+    InvalidArg(String),
+    UnknownFlag(String),
+    MissingValue(String),
+    InvalidValue(String, String),
+    AmbiguousInput,
+    IncompatibleFlags(&'static str, &'static str),
+    ConflictingParts(usize, usize),
+    NoSolutions,
+    CheckFailed(usize, usize),
+    Failed(usize),
+    Regressed(usize),
+    AlreadyExists(PathBuf),
+    NotNextDay(usize, usize, usize),
+    MalformedSource(&'static str),
+    #[cfg(not(feature = "client"))]
+    MissingFeature(String, &'static str),
+    #[cfg(feature = "client")]
+    MissingSession,
+    #[cfg(feature = "client")]
+    UnexpectedResponse(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::WrongArgCount(count) => write!(
+                f,
+                "expected between 0 and 2 arguments (inclusive); got {count}",
+            ),
+            Error::ExtraArgs(command) => write!(f, "{command} takes no arguments"),
+            Error::Usage(usage) => write!(f, "usage: {usage}"),
+            Error::InvalidYear(given, expected) => {
+                write!(f, "years with solutions: {expected:?}; got {given}")
+            }
+            Error::InvalidArg(content) => write!(
+                f,
+                "argument expected to be \".\", a number, a range or a list; got {content}",
+            ),
+            Error::OutOfRange(actual, min, max) => write!(
+                f,
+                "argument expected to be in range ({min}..={max}); got {actual}",
+            ),
+            Error::UnknownFlag(flag) => write!(f, "unknown flag {flag}"),
+            Error::MissingValue(flag) => write!(f, "expected a value after {flag}"),
+            Error::InvalidValue(flag, value) => write!(f, "invalid value for {flag}: {value}"),
+            Error::AmbiguousInput => write!(
+                f,
+                "an input override requires exactly one year and day to be selected",
+            ),
+            Error::IncompatibleFlags(a, b) => write!(f, "{a} can't be combined with {b}"),
+            Error::ConflictingParts(day, flag) => write!(
+                f,
+                "the day argument selects part {} but --part selects part {}",
+                record::PARTS[*day],
+                record::PARTS[*flag],
+            ),
+            Error::NoSolutions => write!(f, "no solutions found"),
+            Error::CheckFailed(wrong, fail) => {
+                write!(f, "check failed: {wrong} wrong and {fail} failed")
+            }
+            Error::Failed(count) => write!(f, "{count} solutions failed"),
+            Error::Regressed(count) => {
+                write!(f, "{count} solutions got slower than the baseline")
+            }
+            Error::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            Error::NotNextDay(year, next, day) => write!(
+                f,
+                "days have to be added in order; the next day for {year} is {next}, got {day}",
+            ),
+            Error::MalformedSource(what) => write!(f, "couldn't find {what}"),
+            #[cfg(not(feature = "client"))]
+            Error::MissingFeature(command, feature) => {
+                write!(f, "{command} requires the `{feature}` feature")
+            }
+            #[cfg(feature = "client")]
+            Error::MissingSession => write!(
+                f,
+                "no session token; set AOC_SESSION or the session field in .aoc/config.json",
+            ),
+            #[cfg(feature = "client")]
+            Error::UnexpectedResponse(text) => write!(f, "unexpected response: {text}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Running totals over the records of a sweep.
+#[derive(Default)]
+struct Tally {
+    success: usize,
+    wrong: usize,
+    fail: usize,
+    regressed: usize,
+    /// The summed run time of all solutions that produced an answer.
+    runtime: Duration,
+    /// How many solutions failed, by [`crate::Error::kind`].
+    failures: BTreeMap<&'static str, usize>,
+}
+
+impl Tally {
+    fn add(&mut self, outcome: &Outcome, time: Duration) {
+        match outcome {
+            Outcome::Success { .. } => {
+                self.runtime += time;
+                self.success += 1;
+            }
+            Outcome::Wrong { .. } => {
+                self.runtime += time;
+                self.wrong += 1;
+            }
+            Outcome::Failed(why) => {
+                *self.failures.entry(why.kind()).or_default() += 1;
+                self.fail += 1;
+            }
+        }
+    }
+
+    fn total(&self) -> usize {
+        self.success + self.wrong + self.fail
+    }
+
+    /// How the sweep ends: failures and regressions always make it unsuccessful, wrong answers
+    /// only when checking.
+    fn status(&self, check: bool) -> std::result::Result<(), Error> {
+        if check && self.wrong + self.fail > 0 {
+            Err(Error::CheckFailed(self.wrong, self.fail))
+        } else if self.fail > 0 {
+            Err(Error::Failed(self.fail))
+        } else if self.regressed > 0 {
+            Err(Error::Regressed(self.regressed))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Runs a range of puzzle solutions. Returns `Ok` if at least one solution was ran, and none of
+/// them failed, regressed or (when checking) gave a wrong answer.
+fn eval<Y, D>(years: Y, days: D, options: &Options) -> Result<()>
+where
+    Y: IntoIterator<Item = usize>,
+    D: Clone + IntoIterator<Item = usize>,
+{
+    let baseline = match &options.baseline {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+    let mut new_baseline = match &options.save_baseline {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+    let threshold = options.threshold.unwrap_or(DEFAULT_THRESHOLD);
+
+    let mut tasks = vec![];
+    for year in years {
+        for day in days.clone() {
+            for part in 0..=1 {
+                if options.part.is_some_and(|p| p != part) {
+                    continue;
+                }
+                if !valid_input(year, day) {
+                    eprintln!("out of range value in eval: ({year}, {day}, {part})");
+                    continue;
+                }
+                tasks.push((year, day, part));
+            }
+        }
+    }
+
+    let start = Instant::now();
+    let mut tally = Tally::default();
+    let mut records = vec![];
+    let mut highest_peak: Option<(usize, String)> = None;
+    let jobs = options.jobs.unwrap_or(1);
+    jobs::run_in_order(&tasks, jobs, options, |mut record| {
+        let (year, day, part) = (record.year, record.day, record.part);
+        if let Some(memory) = record.memory {
+            if highest_peak
+                .as_ref()
+                .is_none_or(|(peak, _)| memory.peak > *peak)
+            {
+                let label = format!("{year:04}-{day:02}{}", record::PARTS[part]);
+                highest_peak = Some((memory.peak, label));
+            }
+        }
+        if !matches!(record.outcome, Outcome::Failed(_)) {
+            if let Some(baseline) = &baseline {
+                record.comparison = baseline.compare(year, day, part, record.time, threshold);
+                if record.comparison.is_some_and(|c| c.regressed) {
+                    tally.regressed += 1;
+                }
+            }
+            if let Some(new_baseline) = &mut new_baseline {
+                new_baseline.insert(year, day, part, record.time);
+            }
+        }
+
+        tally.add(&record.outcome, record.time);
+
+        if options.report.is_some() {
+            records.push(record);
+        } else if options.json {
+            println!("{}", record.to_json());
+        } else {
+            println!("{record}");
+        }
+    })?;
+    let wall_clock = start.elapsed();
+
+    if let Some(report) = &options.report {
+        report.write(&records)?;
+    }
+
+    // The summary would get in the way of output meant for other tools.
+    let to_stdout = options.report.as_ref().is_some_and(|r| r.output.is_none());
+    // Even if everything failed, there's a summary to show as long as anything ran at all.
+    if tally.total() > 0 {
+        if !options.json && !to_stdout {
+            let Tally {
+                success,
+                wrong,
+                fail,
+                ..
+            } = tally;
+            println!(
+                "\nTotal runtime:   {} (success: {success}; wrong: {wrong}; failed: {fail})",
+                format_duration(tally.runtime)
+            );
+
+            if !tally.failures.is_empty() {
+                let failures: Vec<_> = tally
+                    .failures
+                    .iter()
+                    .map(|(kind, count)| format!("{count} {}", kind.replace('_', " ")))
+                    .collect();
+                println!("Failures:        {}", failures.join(", "));
+            }
+
+            if jobs > 1 {
+                println!(
+                    "Wall-clock time: {} ({jobs} jobs)",
+                    format_duration(wall_clock)
+                );
+            }
+
+            if let Some((peak, label)) = &highest_peak {
+                println!("Highest peak:    {} ({label})", memory::format_bytes(*peak));
+            }
+
+            if baseline.is_some() {
+                let regressed = tally.regressed;
+                println!("Regressions:     {regressed} (more than {threshold}% slower)");
+            }
+        }
+
+        if let (Some(path), Some(new_baseline)) = (&options.save_baseline, &new_baseline) {
+            new_baseline.save(path)?;
+        }
+
+        Ok(tally.status(options.check)?)
+    } else {
+        Err(Error::NoSolutions.into())
+    }
+}
+
+/// Runs the solution for a given day, on the input override from `options` if given, or the
+/// embedded input otherwise. If checking is enabled, compares the result to the known answer.
+/// Returns `None` if there is no such solution.
+fn eval_single(year: usize, day: usize, part: usize, options: &Options) -> Option<Record> {
+    if !valid_input(year, day) {
+        eprintln!("out of range input to eval_single ({year}, {day}, {part})");
+        return None;
+    }
+
+    let &(a, b, s, _) = get_solution(year, day)?;
+    let f = match part {
+        0 => a,
+        1 => b,
+        _ => return None,
+    };
+
+    let input = options.input.as_deref().unwrap_or(s);
+    let run = || run_guarded(f, input, options.timeout);
+    let (run, stats) = match options.bench {
+        Some(bench) => bench.run(run),
+        None => (run(), None),
+    };
+    let time = stats.map_or(run.time, |s| s.median);
+
+    let outcome = match run.result {
+        Ok(answer) => match options.check.then(|| get_answer(year, day, part)) {
+            Some(Some(expected)) if expected != answer => Outcome::Wrong { answer, expected },
+            expected => Outcome::Success {
+                answer,
+                unchecked: expected == Some(None),
+            },
+        },
+        Err(why) => Outcome::Failed(why),
+    };
+
+    Some(Record {
+        year,
+        day,
+        part,
+        time,
+        stats,
+        comparison: None,
+        outcome,
+        memory: run.memory,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SolutionFn;
+
+    fn panics(_: &str) -> crate::Result<String> {
+        panic!("boom")
+    }
+
+    fn hangs(_: &str) -> crate::Result<String> {
+        std::thread::sleep(Duration::from_secs(1));
+        Ok("too late".to_string())
+    }
+
+    fn answers(input: &str) -> crate::Result<String> {
+        Ok(input.len().to_string())
+    }
+
+    fn tally(runs: &[(SolutionFn, Option<Duration>)]) -> Tally {
+        let mut tally = Tally::default();
+        for &(f, timeout) in runs {
+            let run = run_guarded(f, "input", timeout);
+            let outcome = match run.result {
+                Ok(answer) => Outcome::Success {
+                    answer,
+                    unchecked: false,
+                },
+                Err(why) => Outcome::Failed(why),
+            };
+            tally.add(&outcome, run.time);
+        }
+        tally
+    }
+
+    #[test]
+    fn failures_are_counted_by_kind() {
+        let timeout = Some(Duration::from_millis(10));
+        let tally = tally(&[(answers, None), (panics, None), (hangs, timeout)]);
+
+        assert_eq!((tally.success, tally.wrong, tally.fail), (1, 0, 2));
+        assert_eq!(tally.total(), 3);
+        let kinds: Vec<_> = tally.failures.into_iter().collect();
+        assert_eq!(kinds, [("panicked", 1), ("timeout", 1)]);
+    }
+
+    #[test]
+    fn failures_fail_the_sweep_without_check() {
+        let timeout = Some(Duration::from_millis(10));
+        let tally = tally(&[(answers, None), (hangs, timeout)]);
+
+        assert!(matches!(tally.status(false), Err(Error::Failed(1))));
+        assert!(matches!(tally.status(true), Err(Error::CheckFailed(0, 1))));
+    }
+
+    #[test]
+    fn successes_pass_the_sweep() {
+        let tally = tally(&[(answers, None), (answers, Some(Duration::from_secs(10)))]);
+
+        assert!(tally.status(true).is_ok());
+        assert!(matches!(
+            Tally {
+                regressed: 1,
+                ..tally
+            }
+            .status(false),
+            Err(Error::Regressed(1))
+        ));
+    }
+
+    /// Parses a day argument; `None` stands for all days.
+    fn days(arg: &str) -> std::result::Result<Option<Vec<usize>>, Error> {
+        match Input::from_day(arg) {
+            Ok(Input::All) => Ok(None),
+            Ok(Input::Specific(days)) => Ok(Some(days)),
+            Err(why) => Err(why.downcast_ref::<Error>().unwrap().clone()),
+        }
+    }
+
+    #[test]
+    fn days_in_ranges_and_lists() {
+        assert_eq!(days("7"), Ok(Some(vec![7])));
+        assert_eq!(days("3-5"), Ok(Some(vec![3, 4, 5])));
+        assert_eq!(days("3..5"), Ok(Some(vec![3, 4, 5])));
+        assert_eq!(days("9,1-3,2"), Ok(Some(vec![1, 2, 3, 9])));
+        assert_eq!(days("4-4"), Ok(Some(vec![4])));
+        assert_eq!(days("."), Ok(None));
+    }
+
+    #[test]
+    fn invalid_days() {
+        assert_eq!(days("10-1"), Err(Error::InvalidArg("10-1".to_string())));
+        assert_eq!(days("1,x"), Err(Error::InvalidArg("1,x".to_string())));
+        assert_eq!(days("1-"), Err(Error::InvalidArg("1-".to_string())));
+        assert_eq!(days("20-26"), Err(Error::OutOfRange(26, 1, 25)));
+    }
+
+    fn part_of(args: &[&str]) -> std::result::Result<(Vec<String>, Option<usize>), Error> {
+        let args = args.iter().map(|arg| arg.to_string());
+        let (mut args, mut options) = Options::parse(args).unwrap();
+        options.take_part_suffix(&mut args)?;
+        Ok((args, options.part))
+    }
+
+    #[test]
+    fn part_from_suffix_or_flag() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect();
+        assert_eq!(part_of(&["2024", "17"]), Ok((args(&["2024", "17"]), None)));
+        assert_eq!(
+            part_of(&["2024", "17b"]),
+            Ok((args(&["2024", "17"]), Some(1)))
+        );
+        assert_eq!(part_of(&["17", "-p", "a"]), Ok((args(&["17"]), Some(0))));
+        assert_eq!(
+            part_of(&["17a", "--part", "a"]),
+            Ok((args(&["17"]), Some(0)))
+        );
+    }
+
+    #[test]
+    fn conflicting_parts() {
+        assert_eq!(
+            part_of(&["2024", "17a", "-p", "b"]),
+            Err(Error::ConflictingParts(0, 1))
+        );
+    }
+}
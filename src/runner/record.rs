@@ -0,0 +1,191 @@
+//! The results of running solutions, and how they are presented.
+
+use std::{borrow::Cow, fmt::Display, time::Duration};
+
+use super::{
+    baseline::Comparison,
+    bench::Stats,
+    memory::{format_bytes, Memory},
+};
+
+/// A record of running a single part of a solution.
+pub struct Record {
+    pub year: usize,
+    pub day: usize,
+    /// 0 for part one, 1 for part two.
+    pub part: usize,
+    /// How long the solution took; the median run time if it was benchmarked.
+    pub time: Duration,
+    /// Statistics over all run times, if the solution was benchmarked.
+    pub stats: Option<Stats>,
+    /// How the run time compares to the baseline, if one was given and has an entry for this.
+    pub comparison: Option<Comparison>,
+    pub outcome: Outcome,
+    /// The memory used by the solution, if measured. For benchmarked solutions, this is from the
+    /// first run.
+    pub memory: Option<Memory>,
+}
+
+/// What running a single part of a solution produced.
+pub enum Outcome {
+    /// The solution produced an answer. It is either correct, or wasn't checked; `unchecked` is
+    /// set if checking was requested, but there is no known answer to compare to.
+    Success { answer: String, unchecked: bool },
+    /// The solution produced an answer that differs from the known one.
+    Wrong { answer: String, expected: String },
+    /// The solution returned an error, or was stopped by the runner.
+    Failed(crate::Error),
+}
+
+/// The labels used for parts one and two in output.
+pub const PARTS: [char; 2] = ['a', 'b'];
+
+impl Record {
+    /// Converts this record into a single-line JSON object.
+    pub fn to_json(&self) -> serde_json::Value {
+        let (status, answer, expected, error) = match &self.outcome {
+            Outcome::Success { answer, unchecked } => {
+                let status = if *unchecked { "unchecked" } else { "success" };
+                (status, Some(answer), None, None)
+            }
+            Outcome::Wrong { answer, expected } => ("wrong", Some(answer), Some(expected), None),
+            Outcome::Failed(why) => ("failed", None, None, Some(why)),
+        };
+        let error_kind = error.map(crate::Error::kind);
+        let error = error.map(ToString::to_string);
+
+        let json = serde_json::json!({
+            "year": self.year,
+            "day": self.day,
+            "part": PARTS[self.part].to_string(),
+            "status": status,
+            "answer": answer,
+            "expected": expected,
+            "error": error,
+            "error_kind": error_kind,
+            "duration_ns": self.time.as_nanos() as u64,
+        });
+
+        let json = match self.stats {
+            Some(stats) => {
+                let mut json = json;
+                json["bench"] = serde_json::json!({
+                    "runs": stats.runs,
+                    "min_ns": stats.min.as_nanos() as u64,
+                    "median_ns": stats.median.as_nanos() as u64,
+                    "mean_ns": stats.mean.as_nanos() as u64,
+                    "stddev_ns": stats.stddev.as_nanos() as u64,
+                });
+                json
+            }
+            None => json,
+        };
+
+        let json = match self.comparison {
+            Some(comparison) => {
+                let mut json = json;
+                json["baseline_ns"] = (comparison.baseline.as_nanos() as u64).into();
+                json["change_percent"] = comparison.change.into();
+                json["regressed"] = comparison.regressed.into();
+                json
+            }
+            None => json,
+        };
+
+        match self.memory {
+            Some(memory) => {
+                let mut json = json;
+                json["memory"] = serde_json::json!({
+                    "peak_bytes": memory.peak,
+                    "total_bytes": memory.total,
+                    "allocations": memory.allocations,
+                });
+                json
+            }
+            None => json,
+        }
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Record { year, day, .. } = self;
+        let part = PARTS[self.part];
+        let d = match self.stats {
+            Some(stats) => Cow::Owned(format_stats(stats)),
+            None => format_duration(self.time),
+        };
+
+        match &self.outcome {
+            Outcome::Success { answer, unchecked } => {
+                write!(f, "Day {year:04}-{day:02}{part}  [{d}]  = {answer}")?;
+                if *unchecked {
+                    write!(f, "  (unchecked)")?;
+                }
+            }
+            Outcome::Wrong { answer, expected } => write!(
+                f,
+                "Day {year:04}-{day:02}{part}  [ WRONG  ]  = {answer} (expected {expected})"
+            )?,
+            Outcome::Failed(why) => write!(f, "Day {year:04}-{day:02}{part}  [ FAILED ]  = {why}")?,
+        }
+
+        if let Some(memory) = self.memory {
+            write!(
+                f,
+                "  (peak {}, total {} in {} allocations)",
+                format_bytes(memory.peak),
+                format_bytes(memory.total),
+                memory.allocations
+            )?;
+        }
+
+        if let Some(comparison) = self.comparison {
+            let slower = if comparison.regressed { "; SLOWER" } else { "" };
+            write!(
+                f,
+                "  ({:+.1}% vs. {}{slower})",
+                comparison.change,
+                format_precise(comparison.baseline).trim()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats benchmark [`Stats`] for output.
+fn format_stats(stats: Stats) -> String {
+    format!(
+        "min {}  median {}  mean {}  sd {}  n={:<3}",
+        format_precise(stats.min),
+        format_precise(stats.median),
+        format_precise(stats.mean),
+        format_precise(stats.stddev),
+        stats.runs
+    )
+}
+
+/// Formats a [`Duration`] for output, with a unit that keeps sub-millisecond precision.
+fn format_precise(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{nanos:>7.0}ns")
+    } else if nanos < 1e6 {
+        format!("{:>7.3}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:>7.3}ms", nanos / 1e6)
+    } else {
+        format!("{:>7.3}s ", nanos / 1e9)
+    }
+}
+
+/// Formats a [`Duration`] for output.
+pub fn format_duration(d: Duration) -> Cow<'static, str> {
+    let d = d.as_secs_f64();
+    if d < 0.001 {
+        Cow::Borrowed("< 0.001s")
+    } else {
+        Cow::Owned(format!("{:>7.3}s", d))
+    }
+}
@@ -0,0 +1,160 @@
+//! The `repl` subcommand, for interactively experimenting with a single day: running its parts
+//! on a modified input, or with different parameters (see [`param`](crate::common::param)),
+//! without recompiling.
+
+use std::io::{BufRead, Write};
+
+use regex::Regex;
+
+use super::{
+    get_solution, guard::run_guarded, parse_puzzle, record::PARTS, Error, Options, Result,
+};
+use crate::common::{param_override, set_param};
+
+/// Shown for `help`.
+const HELP: &str = "\
+a, b, run            run part one, part two, or both on the current input
+show [N[-M]]         show lines N to M of the input (by default, the first 20)
+set N TEXT           replace line N with TEXT
+insert N TEXT        insert TEXT as a new line before line N
+delete N[-M]         delete lines N to M
+replace OLD NEW      replace every occurrence of OLD in the input with NEW
+load PATH            use the file at PATH (not `-`) as the input
+reset                go back to the original input
+params               list the parameters the solution uses, and their values
+param NAME [VALUE]   override a parameter, or remove its override if no VALUE is given
+help                 show this
+quit                 leave (as does end of input)";
+
+/// Starts an interactive session for `day` of `year` (both as given on the command line), on the
+/// input override from `options` if given, or the embedded input otherwise.
+pub fn run(year: &str, day: &str, options: &Options) -> Result<()> {
+    let (year, day) = parse_puzzle(year, day)?;
+    let solution = get_solution(year, day).ok_or(Error::NoSolutions)?;
+    let original = options.input.as_deref().unwrap_or(solution.2);
+    let mut lines: Vec<String> = original.lines().map(String::from).collect();
+
+    println!(
+        "{year} day {day}, {} lines of input; try `help`.",
+        lines.len()
+    );
+    let mut stdin = std::io::stdin().lock();
+    loop {
+        print!("> ");
+        std::io::stdout().flush()?;
+        let mut line = String::new();
+        if stdin.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+
+        let line = line.trim();
+        let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let rest = rest.trim();
+        match command {
+            "" => {}
+            "a" | "b" | "run" => {
+                let input = lines.join("\n") + "\n";
+                let parts = match command {
+                    "a" => 0..=0,
+                    "b" => 1..=1,
+                    _ => 0..=1,
+                };
+                for part in parts {
+                    let f = [solution.0, solution.1][part];
+                    let run = run_guarded(f, &input, options.timeout);
+                    let time = super::format_duration(run.time);
+                    match run.result {
+                        Ok(answer) => println!("{}  [{time}]  = {answer}", PARTS[part]),
+                        Err(why) => println!("{}  [ FAILED ]  = {why}", PARTS[part]),
+                    }
+                }
+            }
+            "show" => {
+                let (from, to) = match rest {
+                    "" => (1, 20),
+                    range => match parse_range(range, lines.len()) {
+                        Some(range) => range,
+                        None => continue,
+                    },
+                };
+                for (i, line) in lines.iter().enumerate().take(to).skip(from - 1) {
+                    println!("{:>5} {line}", i + 1);
+                }
+                if to < lines.len() {
+                    println!("      ({} more lines)", lines.len() - to);
+                }
+            }
+            "set" | "insert" => {
+                let (n, text) = rest.split_once(' ').unwrap_or((rest, ""));
+                let limit = lines.len() + usize::from(command == "insert");
+                let Some((n, _)) = parse_range(n, limit) else {
+                    continue;
+                };
+                match command {
+                    "set" => lines[n - 1] = text.to_string(),
+                    _ => lines.insert(n - 1, text.to_string()),
+                }
+            }
+            "delete" => {
+                if let Some((from, to)) = parse_range(rest, lines.len()) {
+                    lines.drain(from - 1..to);
+                }
+            }
+            "replace" => match rest.split_once(' ') {
+                Some((old, new)) => {
+                    let count: usize = lines.iter().map(|line| line.matches(old).count()).sum();
+                    for line in &mut lines {
+                        *line = line.replace(old, new.trim());
+                    }
+                    println!("Replaced {count} occurrences");
+                }
+                None => println!("usage: replace OLD NEW"),
+            },
+            // Reading the input from standard input would use up the commands too.
+            "load" if rest == "-" => println!("can't load from standard input here"),
+            "load" => match super::read_input(rest) {
+                Ok(input) => lines = input.lines().map(String::from).collect(),
+                Err(why) => println!("{why}"),
+            },
+            "reset" => lines = original.lines().map(String::from).collect(),
+            "params" => {
+                for (name, default) in params(solution.3) {
+                    match param_override(&name) {
+                        Some(value) => println!("{name} = {value} (default {default})"),
+                        None => println!("{name} = {default}"),
+                    }
+                }
+            }
+            "param" => match rest.split_once(' ') {
+                Some((name, value)) => set_param(name, Some(value.trim().to_string())),
+                None if !rest.is_empty() => set_param(rest, None),
+                None => println!("usage: param NAME [VALUE]"),
+            },
+            "help" => println!("{HELP}"),
+            "quit" | "exit" => return Ok(()),
+            _ => println!("unknown command {command}; try `help`"),
+        }
+    }
+}
+
+/// Parses a 1-based line number `N` or inclusive range `N-M` with `M` at most `limit`. Prints why
+/// and returns `None` if it isn't valid.
+fn parse_range(range: &str, limit: usize) -> Option<(usize, usize)> {
+    let (from, to) = range.split_once('-').unwrap_or((range, range));
+    match (from.parse(), to.parse()) {
+        (Ok(from), Ok(to)) if 1 <= from && from <= to && to <= limit => Some((from, to)),
+        _ => {
+            println!("expected a line number or range within 1-{limit}; got {range}");
+            None
+        }
+    }
+}
+
+/// Finds all parameters used in `source`, with their defaults, from calls like
+/// `param("steps", 64)`.
+fn params(source: &str) -> Vec<(String, String)> {
+    let call = Regex::new(r#"param\("([^"]+)",\s*([^)]+)\)"#).expect("the regex is valid");
+    call.captures_iter(source)
+        .map(|captures| (captures[1].to_string(), captures[2].trim().to_string()))
+        .collect()
+}
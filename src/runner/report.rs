@@ -0,0 +1,159 @@
+//! Reports over a selection of solutions, as a Markdown table per year, and optionally as a
+//! self-contained HTML page with the same contents.
+
+use std::path::PathBuf;
+
+use super::{
+    get_solution,
+    record::{format_duration, Outcome, Record, PARTS},
+    source, Result,
+};
+
+/// Settings for writing a report.
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    /// Where to write the Markdown report; standard output if not given.
+    pub output: Option<PathBuf>,
+    /// If present, the report is also written here as an HTML page.
+    pub html: Option<PathBuf>,
+}
+
+/// The column headers of every table.
+#[cfg(not(feature = "peak_alloc"))]
+const HEADERS: &[&str] = &["Day", "Part", "Description", "Answer", "Time"];
+#[cfg(feature = "peak_alloc")]
+const HEADERS: &[&str] = &[
+    "Day",
+    "Part",
+    "Description",
+    "Answer",
+    "Time",
+    "Peak memory",
+];
+
+impl Report {
+    /// Writes the report for all `records`, which are expected to be in year/day/part order.
+    pub fn write(&self, records: &[Record]) -> Result<()> {
+        let tables = tables(records);
+
+        let markdown = markdown(&tables);
+        match &self.output {
+            Some(path) => std::fs::write(path, markdown)?,
+            None => print!("{markdown}"),
+        }
+        if let Some(path) = &self.html {
+            std::fs::write(path, html(&tables))?;
+        }
+        Ok(())
+    }
+}
+
+/// The rows of the table for a year, each holding one cell per entry in [`HEADERS`].
+type Table = (usize, Vec<Vec<String>>);
+
+/// Splits `records` into one table per year.
+fn tables(records: &[Record]) -> Vec<Table> {
+    let mut tables: Vec<Table> = vec![];
+    for record in records {
+        if tables.last().is_none_or(|(year, _)| *year != record.year) {
+            tables.push((record.year, vec![]));
+        }
+        tables.last_mut().unwrap().1.push(row(record));
+    }
+    tables
+}
+
+/// The cells of the table row for a single record.
+fn row(record: &Record) -> Vec<String> {
+    // The first paragraph of the doc comment, which is wrapped over several lines when long.
+    let source = get_solution(record.year, record.day).map_or("", |solution| solution.3);
+    let doc = source::doc_comment(source, ["one", "two"][record.part]).unwrap_or_default();
+    let lines = doc.lines().take_while(|line| !line.trim().is_empty());
+    let description = lines.collect::<Vec<_>>().join(" ");
+
+    let (answer, time) = match &record.outcome {
+        Outcome::Success { answer, .. } => (answer.clone(), format_duration(record.time)),
+        Outcome::Wrong { answer, expected } => (
+            format!("{answer} (wrong; expected {expected})"),
+            format_duration(record.time),
+        ),
+        Outcome::Failed(why) => (format!("failed: {why}"), "-".into()),
+    };
+
+    let row = vec![
+        record.day.to_string(),
+        PARTS[record.part].to_string(),
+        description,
+        answer,
+        time.trim().to_string(),
+    ];
+    #[cfg(feature = "peak_alloc")]
+    let row = {
+        let mut row = row;
+        let peak = record
+            .memory
+            .map(|memory| super::memory::format_bytes(memory.peak));
+        row.push(peak.unwrap_or_else(|| "-".to_string()));
+        row
+    };
+    row
+}
+
+/// Formats the report as Markdown.
+fn markdown(tables: &[Table]) -> String {
+    let line = |cells: &mut dyn Iterator<Item = &str>| {
+        let cells: Vec<_> = cells
+            .map(|cell| cell.replace('|', "\\|").replace('\n', " "))
+            .collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+
+    let mut out = String::from("# Advent of Code\n");
+    for (year, rows) in tables {
+        out += &format!("\n## {year}\n\n");
+        out += &line(&mut HEADERS.iter().copied());
+        out += &line(&mut HEADERS.iter().map(|_| "---"));
+        for row in rows {
+            out += &line(&mut row.iter().map(String::as_str));
+        }
+    }
+    out
+}
+
+/// Formats the report as an HTML page, with its styling included.
+fn html(tables: &[Table]) -> String {
+    let line = |tag: &str, cells: &mut dyn Iterator<Item = &str>| {
+        let cells: Vec<_> = cells
+            .map(|cell| format!("<{tag}>{}</{tag}>", escape(cell)))
+            .collect();
+        format!("<tr>{}</tr>\n", cells.concat())
+    };
+
+    let mut out = String::from(concat!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n",
+        "<title>Advent of Code</title>\n<style>\n",
+        "body { font-family: sans-serif; margin: 2em; }\n",
+        "table { border-collapse: collapse; margin-bottom: 2em; }\n",
+        "th, td { border: 1px solid #ccc; padding: 0.2em 0.6em; text-align: left; }\n",
+        "th { background: #eee; }\n",
+        "</style>\n</head>\n<body>\n<h1>Advent of Code</h1>\n",
+    ));
+    for (year, rows) in tables {
+        out += &format!("<h2>{year}</h2>\n<table>\n");
+        out += &line("th", &mut HEADERS.iter().copied());
+        for row in rows {
+            out += &line("td", &mut row.iter().map(String::as_str));
+        }
+        out += "</table>\n";
+    }
+    out += "</body>\n</html>\n";
+    out
+}
+
+/// Escapes the characters that have a special meaning in HTML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,264 @@
+//! The `new` subcommand, which sets up all files for a new day and registers it in `events!`.
+
+use std::path::Path;
+
+use super::{parse_puzzle, solution_path, Error, Result, SOURCE_DIR};
+
+/// Sets up `day` of `year` (both as given on the command line): creates the solution file from
+/// `src/solutions/template.rs`, an empty input file and an empty examples file, and registers the
+/// day in the `events!` invocation in `lib.rs`. A new year additionally gets an answers file and
+/// its own cargo feature.
+///
+/// Nothing is written unless all of the changes can be made.
+pub fn create(year: &str, day: &str) -> Result<()> {
+    let (year, day) = parse_puzzle(year, day)?;
+
+    let root = Path::new(SOURCE_DIR);
+    let solution = solution_path(root, year, day);
+    if solution.exists() {
+        return Err(Error::AlreadyExists(solution).into());
+    }
+
+    let lib_path = root.join("lib.rs");
+    let (lib, new_year) = register(&std::fs::read_to_string(&lib_path)?, year, day)?;
+    let manifest_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let manifest = match new_year {
+        true => Some(add_feature(
+            &std::fs::read_to_string(&manifest_path)?,
+            year,
+        )?),
+        false => None,
+    };
+    let template = std::fs::read_to_string(root.join("solutions/template.rs"))?;
+
+    // Everything checks out, so now actually make the changes.
+    let data = root.join(format!("data/aoc{year}"));
+    std::fs::create_dir_all(solution.parent().unwrap())?;
+    std::fs::create_dir_all(&data)?;
+
+    std::fs::write(&solution, template)?;
+    println!("Created {}", solution.display());
+    for (name, contents) in [
+        (format!("day{day:02}.txt"), ""),
+        (format!("day{day:02}_examples.txt"), ""),
+        ("answers.json".to_string(), "{}\n"),
+    ] {
+        let path = data.join(name);
+        if !path.exists() {
+            std::fs::write(&path, contents)?;
+            println!("Created {}", path.display());
+        }
+    }
+
+    std::fs::write(&lib_path, lib)?;
+    println!("Registered {year} day {day} in {}", lib_path.display());
+    if let Some(manifest) = manifest {
+        std::fs::write(&manifest_path, manifest)?;
+        println!("Added the y{year} feature to {}", manifest_path.display());
+    }
+
+    Ok(())
+}
+
+/// Adds `day` of `year` to the `events!` invocation in `lib`, the contents of `lib.rs`. Returns
+/// the changed contents, and whether the year had to be added as well.
+///
+/// Days are looked up by their position in the list, so they have to be added in order.
+fn register(lib: &str, year: usize, day: usize) -> Result<(String, bool)> {
+    let malformed = || Error::MalformedSource("the `events!` invocation in lib.rs");
+
+    let mut lines: Vec<_> = lib.lines().map(String::from).collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("events! {"))
+        .ok_or_else(malformed)?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "}")
+            .ok_or_else(malformed)?;
+
+    // Each year is on its own line, like `    2025 if "y2025" => aoc2025::{day01, day02};`.
+    let line_year = |line: &str| line.trim().split(' ').next()?.parse::<usize>().ok();
+
+    let registered = (start + 1..end).find(|&i| line_year(&lines[i]) == Some(year));
+    let new_year = registered.is_none();
+    match registered {
+        Some(i) => {
+            let (head, days) = lines[i].split_once('{').ok_or_else(malformed)?;
+            let days = days.strip_suffix("};").ok_or_else(malformed)?;
+            let next = days.split(',').count() + 1;
+            if day != next {
+                return Err(Error::NotNextDay(year, next, day).into());
+            }
+            lines[i] = format!("{head}{{{days}, day{day:02}}};");
+        }
+        None => {
+            if day != 1 {
+                return Err(Error::NotNextDay(year, 1, day).into());
+            }
+            let at = (start + 1..end)
+                .find(|&i| line_year(&lines[i]).is_some_and(|y| y > year))
+                .unwrap_or(end);
+            let line = format!("    {year} if \"y{year}\" => aoc{year}::{{day01}};");
+            lines.insert(at, line);
+        }
+    }
+
+    Ok((lines.join("\n") + "\n", new_year))
+}
+
+/// Adds a `y{year}` feature to `manifest`, the contents of `Cargo.toml`, and includes it in
+/// `all-years`. If `year` is the newest one, it also becomes the default, replacing the previous
+/// newest year.
+fn add_feature(manifest: &str, year: usize) -> Result<String> {
+    let malformed = || Error::MalformedSource("the year features in Cargo.toml");
+
+    let mut lines: Vec<_> = manifest.lines().map(String::from).collect();
+    let feature_year = |line: &str| {
+        let (name, _) = line.split_once(" = ")?;
+        name.strip_prefix('y')?.parse::<usize>().ok()
+    };
+
+    let years: Vec<_> = lines.iter().filter_map(|line| feature_year(line)).collect();
+    let newest = years.iter().all(|&y| y < year);
+
+    // The feature itself goes in between its neighbouring years.
+    let at = lines
+        .iter()
+        .rposition(|line| feature_year(line).is_some_and(|y| y < year))
+        .map(|i| i + 1)
+        .or_else(|| lines.iter().position(|line| feature_year(line).is_some()))
+        .ok_or_else(malformed)?;
+    lines.insert(at, format!("y{year} = []"));
+
+    let all_years = lines
+        .iter_mut()
+        .find(|line| line.starts_with("all-years = ["))
+        .ok_or_else(malformed)?;
+    let mut features: Vec<_> = years.iter().map(|y| format!("\"y{y}\"")).collect();
+    features.push(format!("\"y{year}\""));
+    features.sort();
+    *all_years = format!("all-years = [{}]", features.join(", "));
+
+    if newest {
+        let default = lines
+            .iter_mut()
+            .find(|line| line.starts_with("default = ["))
+            .ok_or_else(malformed)?;
+        *default = format!("default = [\"y{year}\"]");
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = r#"mod registry;
+
+events! {
+    2015 if "y2015" => aoc2015::{day01, day02};
+    2017 if "y2017" => aoc2017::{day01, #[ignore] day02, day03};
+}
+
+const ASSUMED_YEAR: usize = 2017;
+"#;
+
+    const MANIFEST: &str = r#"[features]
+default = ["y2017"]
+all-years = ["y2015", "y2017"]
+y2015 = []
+y2017 = []
+
+[profile.test]
+"#;
+
+    fn error<T>(result: Result<T>) -> Error {
+        let why = result.err().expect("expected an error");
+        why.downcast_ref::<Error>().unwrap().clone()
+    }
+
+    #[test]
+    fn register_next_day() {
+        let (lib, new_year) = register(LIB, 2015, 3).unwrap();
+        assert!(!new_year);
+        assert!(lib.contains("    2015 if \"y2015\" => aoc2015::{day01, day02, day03};\n"));
+        assert!(lib.ends_with("const ASSUMED_YEAR: usize = 2017;\n"));
+    }
+
+    #[test]
+    fn ignored_days_count() {
+        let (lib, _) = register(LIB, 2017, 4).unwrap();
+        assert!(lib.contains("aoc2017::{day01, #[ignore] day02, day03, day04};"));
+    }
+
+    #[test]
+    fn register_out_of_order() {
+        assert_eq!(error(register(LIB, 2017, 3)), Error::NotNextDay(2017, 4, 3));
+        assert_eq!(error(register(LIB, 2015, 5)), Error::NotNextDay(2015, 3, 5));
+        assert_eq!(error(register(LIB, 2016, 2)), Error::NotNextDay(2016, 1, 2));
+    }
+
+    #[test]
+    fn register_new_year_in_order() {
+        let (lib, new_year) = register(LIB, 2016, 1).unwrap();
+        assert!(new_year);
+        let years: Vec<_> = lib.lines().skip(3).take(3).collect();
+        assert_eq!(
+            years,
+            [
+                "    2015 if \"y2015\" => aoc2015::{day01, day02};",
+                "    2016 if \"y2016\" => aoc2016::{day01};",
+                "    2017 if \"y2017\" => aoc2017::{day01, #[ignore] day02, day03};",
+            ]
+        );
+
+        let (lib, _) = register(LIB, 2018, 1).unwrap();
+        let line = lib.lines().nth(5).unwrap();
+        assert_eq!(line, "    2018 if \"y2018\" => aoc2018::{day01};");
+    }
+
+    #[test]
+    fn register_without_events() {
+        assert_eq!(
+            error(register("mod registry;\n", 2015, 3)),
+            Error::MalformedSource("the `events!` invocation in lib.rs")
+        );
+    }
+
+    #[test]
+    fn feature_for_older_year() {
+        let manifest = add_feature(MANIFEST, 2016).unwrap();
+        assert_eq!(
+            manifest,
+            r#"[features]
+default = ["y2017"]
+all-years = ["y2015", "y2016", "y2017"]
+y2015 = []
+y2016 = []
+y2017 = []
+
+[profile.test]
+"#
+        );
+    }
+
+    #[test]
+    fn feature_for_newest_year() {
+        let manifest = add_feature(MANIFEST, 2018).unwrap();
+        assert_eq!(
+            manifest,
+            r#"[features]
+default = ["y2018"]
+all-years = ["y2015", "y2017", "y2018"]
+y2015 = []
+y2017 = []
+y2018 = []
+
+[profile.test]
+"#
+        );
+    }
+}
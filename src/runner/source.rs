@@ -0,0 +1,64 @@
+//! Rudimentary analysis of solution source code, to find out things the compiler can't tell us,
+//! like doc comments or whether a part is still a placeholder.
+
+/// How far along a single part of a solution is, judging by its source code.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// The part has an actual implementation.
+    Solved,
+    /// The part is unchanged from `template.rs`, returning [`crate::Error::Unimplemented`].
+    Unimplemented,
+    /// The part has no puzzle to solve (usually day 25 part two) and just returns a placeholder.
+    Freebie,
+}
+
+/// Determines the [`Status`] of the part implemented by the function `name` (`one` or `two`)
+/// in `source`. Parts that can't be found count as unimplemented.
+pub fn status(source: &str, name: &str) -> Status {
+    if doc_comment(source, name).is_some_and(|doc| doc.starts_with("Freebie!")) {
+        Status::Freebie
+    } else if body(source, name).is_none_or(|body| body.contains("Error::Unimplemented")) {
+        Status::Unimplemented
+    } else {
+        Status::Solved
+    }
+}
+
+/// Returns the doc comment of the public function `name` in `source`, with the leading `///`
+/// and one space stripped from each line; or `None` if it has none.
+pub fn doc_comment(source: &str, name: &str) -> Option<String> {
+    let lines: Vec<_> = source.lines().collect();
+    let index = lines.iter().position(|line| is_signature(line, name))?;
+
+    let doc: Vec<_> = lines[..index]
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .skip_while(|line| line.starts_with("#["))
+        .map_while(|line| line.strip_prefix("///"))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect();
+
+    (!doc.is_empty()).then(|| doc.into_iter().rev().collect::<Vec<_>>().join("\n"))
+}
+
+/// Returns the source of the public function `name` in `source`, from its signature up to and
+/// including the closing brace, assuming it is formatted by rustfmt.
+fn body<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let start = source
+        .lines()
+        .find(|line| is_signature(line, name))
+        .map(|line| line.as_ptr() as usize - source.as_ptr() as usize)?;
+    let end = source[start..]
+        .find("\n}")
+        .map_or(source.len(), |end| start + end + 2);
+
+    Some(&source[start..end])
+}
+
+/// Checks whether `line` is the signature of the public top-level function `name`.
+fn is_signature(line: &str, name: &str) -> bool {
+    line.strip_prefix("pub fn ")
+        .and_then(|rest| rest.strip_prefix(name))
+        .is_some_and(|rest| rest.starts_with(['(', '<']))
+}
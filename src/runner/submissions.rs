@@ -0,0 +1,221 @@
+//! The local record of submitted answers, which makes sure the same wrong answer is never sent to
+//! the server twice.
+
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use super::Result;
+
+/// The server's response to a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Right,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The previous submission was too recent; the next one is possible after the given time.
+    Wait(Duration),
+    /// The part was already solved, so there is nothing to submit.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// The name of the verdict, as stored in the submissions file.
+    fn name(self) -> Option<&'static str> {
+        match self {
+            Verdict::Right => Some("right"),
+            Verdict::TooHigh => Some("too high"),
+            Verdict::TooLow => Some("too low"),
+            Verdict::Wrong => Some("wrong"),
+            Verdict::Wait(_) | Verdict::AlreadySolved => None,
+        }
+    }
+
+    /// The inverse of [`Verdict::name`].
+    fn from_name(name: &str) -> Option<Verdict> {
+        [
+            Verdict::Right,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+        ]
+        .into_iter()
+        .find(|v| v.name() == Some(name))
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Wait(time) => write!(f, "wait {}s before submitting again", time.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            verdict => write!(f, "{}", verdict.name().unwrap_or_default()),
+        }
+    }
+}
+
+/// All verdicts received so far, as kept in the submissions file.
+///
+/// The file is a JSON object with two fields: `verdicts`, mapping keys like `"2024-17a"` to an
+/// object from submitted answers to their verdicts; and `wait_until`, the UNIX time (in seconds)
+/// before which the server won't accept another submission.
+#[derive(Default)]
+pub struct Submissions {
+    verdicts: BTreeMap<String, BTreeMap<String, String>>,
+    wait_until: u64,
+}
+
+impl Submissions {
+    /// Loads the submissions from the file at `path`. A missing file is treated as having no
+    /// submissions.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let value: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        Ok(Self {
+            verdicts: serde_json::from_value(value["verdicts"].clone()).unwrap_or_default(),
+            wait_until: value["wait_until"].as_u64().unwrap_or(0),
+        })
+    }
+
+    /// Writes the submissions to the file at `path`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let value = serde_json::json!({
+            "verdicts": self.verdicts,
+            "wait_until": self.wait_until,
+        });
+        Ok(std::fs::write(path, serde_json::to_string_pretty(&value)?)?)
+    }
+
+    /// Records the verdict for `answer` to the given solution part. A [`Verdict::Wait`] is
+    /// remembered as the earliest time for the next submission instead.
+    pub fn insert(&mut self, year: usize, day: usize, part: usize, answer: &str, verdict: Verdict) {
+        if let Verdict::Wait(time) = verdict {
+            self.wait_until = now() + time.as_secs();
+        } else if let Some(name) = verdict.name() {
+            self.verdicts
+                .entry(key(year, day, part))
+                .or_default()
+                .insert(answer.to_string(), name.to_string());
+        }
+    }
+
+    /// Checks whether submitting `answer` to the given solution part would be pointless, based on
+    /// the verdicts so far. If so, returns why: the verdict the same answer already got, or that
+    /// the part is solved already, or that a numeric answer is out of the bounds established by
+    /// earlier "too high" and "too low" verdicts. Also refuses while the server wants us to wait.
+    pub fn check(&self, year: usize, day: usize, part: usize, answer: &str) -> Option<String> {
+        let verdicts = self.verdicts.get(&key(year, day, part));
+        let verdicts = verdicts.into_iter().flatten();
+        let verdicts: Vec<_> = verdicts
+            .filter_map(|(answer, verdict)| Some((answer, Verdict::from_name(verdict)?)))
+            .collect();
+
+        if let Some((_, verdict)) = verdicts.iter().find(|(a, _)| *a == answer) {
+            return Some(format!("{answer} was already submitted: {verdict}"));
+        }
+        if let Some((right, _)) = verdicts.iter().find(|(_, v)| *v == Verdict::Right) {
+            return Some(format!("already solved, the answer was {right}"));
+        }
+        if let Ok(number) = answer.parse::<i64>() {
+            for (previous, verdict) in &verdicts {
+                let Ok(previous) = previous.parse::<i64>() else {
+                    continue;
+                };
+                match verdict {
+                    Verdict::TooHigh if number >= previous => {
+                        return Some(format!("{answer} is too high, {previous} already was"));
+                    }
+                    Verdict::TooLow if number <= previous => {
+                        return Some(format!("{answer} is too low, {previous} already was"));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if self.wait_until > now() {
+            return Some(format!(
+                "wait {}s before submitting again",
+                self.wait_until - now()
+            ));
+        }
+
+        None
+    }
+}
+
+/// The key used for a solution part in the submissions file.
+fn key(year: usize, day: usize, part: usize) -> String {
+    format!("{year:04}-{day:02}{}", super::record::PARTS[part])
+}
+
+/// The current UNIX time, in seconds.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submissions(verdicts: &[(&str, Verdict)]) -> Submissions {
+        let mut submissions = Submissions::default();
+        for &(answer, verdict) in verdicts {
+            submissions.insert(2024, 17, 0, answer, verdict);
+        }
+        submissions
+    }
+
+    #[test]
+    fn refuses_duplicates() {
+        let submissions = submissions(&[("abc", Verdict::Wrong)]);
+        let refusal = submissions.check(2024, 17, 0, "abc");
+        assert_eq!(refusal.as_deref(), Some("abc was already submitted: wrong"));
+        assert_eq!(submissions.check(2024, 17, 0, "abd"), None);
+        assert_eq!(submissions.check(2024, 17, 1, "abc"), None);
+    }
+
+    #[test]
+    fn refuses_when_solved() {
+        let submissions = submissions(&[("7", Verdict::Right)]);
+        let refusal = submissions.check(2024, 17, 0, "8");
+        assert_eq!(refusal.as_deref(), Some("already solved, the answer was 7"));
+    }
+
+    #[test]
+    fn refuses_out_of_bounds() {
+        let submissions = submissions(&[("100", Verdict::TooHigh), ("10", Verdict::TooLow)]);
+        let check = |answer| submissions.check(2024, 17, 0, answer);
+        assert_eq!(
+            check("150").as_deref(),
+            Some("150 is too high, 100 already was")
+        );
+        assert_eq!(check("5").as_deref(), Some("5 is too low, 10 already was"));
+        assert_eq!(
+            check("10").as_deref(),
+            Some("10 was already submitted: too low")
+        );
+        assert_eq!(check("11"), None);
+        assert_eq!(check("99"), None);
+        assert_eq!(check("x"), None);
+    }
+
+    #[test]
+    fn refuses_while_waiting() {
+        let mut submissions = Submissions::default();
+        submissions.insert(2024, 17, 0, "1", Verdict::Wait(Duration::from_secs(60)));
+        let refusal = submissions.check(2024, 17, 0, "2").unwrap();
+        assert!(refusal.starts_with("wait "), "{refusal}");
+        assert_eq!(submissions.verdicts.len(), 0);
+    }
+}
@@ -0,0 +1,159 @@
+//! The `watch` subcommand, which reruns a single day whenever its solution, input or examples
+//! change, for a quicker loop while solving.
+//!
+//! Since solutions are compiled into the binary, every rerun goes through `cargo run`, with the
+//! same features and profile as the running binary, but a separate target directory. Changes are
+//! found by polling modification times, which works the same everywhere.
+
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, SystemTime},
+};
+
+use super::{
+    get_solution, parse_puzzle, record::format_duration, solution_path, split_part, Error, Result,
+    SOURCE_DIR,
+};
+use crate::{ASSUMED_YEAR, CONTENTS};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watches the day given by `args` (the positional arguments after `watch`), which works like
+/// for a normal run, except that it has to select a single day: `[[YEAR] DAY]`. Runs until
+/// interrupted.
+pub fn run(args: &[String]) -> Result<()> {
+    let (year, day) = match args {
+        [] => {
+            let day = (1..=25)
+                .rev()
+                .find(|&day| get_solution(ASSUMED_YEAR, day).is_some());
+            (ASSUMED_YEAR, day.ok_or(Error::NoSolutions)?)
+        }
+        [day] => parse_puzzle(&ASSUMED_YEAR.to_string(), split_part(day).0)?,
+        [year, day] => parse_puzzle(year, split_part(day).0)?,
+        _ => return Err(Error::Usage("watch [[YEAR] DAY]").into()),
+    };
+
+    let root = Path::new(SOURCE_DIR);
+    let files = [
+        solution_path(root, year, day),
+        root.join(format!("data/aoc{year}/day{day:02}.txt")),
+        root.join(format!("data/aoc{year}/day{day:02}_examples.txt")),
+    ];
+    println!("Watching {year} day {day}; press Ctrl+C to stop.");
+
+    let mut previous: [Option<String>; 2] = [None, None];
+    let mut times = modification_times(&files);
+    loop {
+        rerun(year, day, &mut previous)?;
+
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            let new_times = modification_times(&files);
+            if let Some(i) = (0..files.len()).find(|&i| new_times[i] != times[i]) {
+                let name = files[i].strip_prefix(root).unwrap_or(&files[i]);
+                println!("\n{} changed", name.display());
+                times = new_times;
+                break;
+            }
+        }
+    }
+}
+
+/// The modification time of each of `files`; `None` for files that don't exist.
+fn modification_times(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| {
+            std::fs::metadata(file)
+                .and_then(|meta| meta.modified())
+                .ok()
+        })
+        .collect()
+}
+
+/// Rebuilds and runs the solution through `cargo run`, and prints the results next to the answers
+/// from the previous run, which are then replaced in `previous`.
+fn rerun(year: usize, day: usize, previous: &mut [Option<String>; 2]) -> Result<()> {
+    let output = cargo_run(year)
+        .stderr(Stdio::inherit())
+        .stdout(Stdio::piped())
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let records: Vec<serde_json::Value> = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    if records.is_empty() {
+        println!("Nothing to show for {year} day {day}; see above for why.");
+        return Ok(());
+    }
+
+    for record in records {
+        let part = usize::from(record["part"] == "b");
+        let label = format!("Day {year:04}-{day:02}{}", ["a", "b"][part]);
+        let time = record["duration_ns"].as_u64().unwrap_or(0);
+        let time = format_duration(Duration::from_nanos(time));
+
+        let Some(answer) = record["answer"].as_str() else {
+            let error = record["error"].as_str().unwrap_or("unknown error");
+            println!("{label}  [ FAILED ]  = {error}");
+            continue;
+        };
+        let change = match &previous[part] {
+            None => String::new(),
+            Some(old) if old == answer => "  (unchanged)".to_string(),
+            Some(old) => format!("  (was {old})"),
+        };
+        let wrong = match record["expected"].as_str() {
+            Some(expected) => format!("  WRONG, expected {expected}"),
+            None => String::new(),
+        };
+        println!("{label}  [{time}]  = {answer}{change}{wrong}");
+        previous[part] = Some(answer.to_string());
+    }
+
+    Ok(())
+}
+
+/// A `cargo run` command for the solutions of the day, built like the running binary (but with the
+/// feature for `year` in any case), and passing on all of its command line flags.
+fn cargo_run(year: usize) -> Command {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command.args(["run", "--quiet", "--manifest-path"]);
+    command.arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"));
+    // Building into the usual target directory would overwrite the running binary, which Windows
+    // doesn't allow; so reruns get a directory of their own.
+    let target = std::env::var_os("CARGO_TARGET_DIR").map_or_else(
+        || Path::new(env!("CARGO_MANIFEST_DIR")).join("target"),
+        PathBuf::from,
+    );
+    command.arg("--target-dir").arg(target.join("watch"));
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    let mut features: Vec<_> = CONTENTS.iter().map(|c| format!("y{}", c.0)).collect();
+    if !CONTENTS.iter().any(|c| c.0 == year) {
+        features.push(format!("y{year}"));
+    }
+    if cfg!(feature = "peak_alloc") {
+        features.push("peak_alloc".to_string());
+    }
+    if cfg!(feature = "client") {
+        features.push("client".to_string());
+    }
+    command.args(["--no-default-features", "--features", &features.join(",")]);
+
+    // Everything but the `watch` itself is passed on, so selection and flags work the same.
+    let mut args: Vec<_> = std::env::args().skip(1).collect();
+    if let Some(i) = args.iter().position(|arg| arg == "watch") {
+        args.remove(i);
+    }
+    command.arg("--").args(args).arg("--json");
+    command
+}
@@ -5,7 +5,7 @@ pub fn one(input: &str) -> crate::Result<usize> {
     let (locations, distances) = parse(input)?;
     all_route_lengths(locations, distances)
         .min()
-        .ok_or_else(|| "no input locations".into())
+        .ok_or_else(|| crate::Error::invalid_input("no input locations"))
 }
 
 /// Find the longest possible route that visits all cities.
@@ -13,7 +13,7 @@ pub fn two(input: &str) -> crate::Result<usize> {
     let (locations, distances) = parse(input)?;
     all_route_lengths(locations, distances)
         .max()
-        .ok_or_else(|| "no input locations".into())
+        .ok_or_else(|| crate::Error::invalid_input("no input locations"))
 }
 
 type Distances = HashMap<(usize, usize), usize>;
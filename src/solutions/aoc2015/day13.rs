@@ -5,7 +5,7 @@ pub fn one(input: &str) -> crate::Result<i32> {
     let (people, matrix) = parse(input).ok_or_else(|| "failed to parse puzzle input".to_owned())?;
     all_arrangement_values(people, matrix)
         .max()
-        .ok_or_else(|| "no people in input".into())
+        .ok_or_else(|| crate::Error::invalid_input("no people in input"))
 }
 
 /// Find the best happiness rating achievable with a seating arrangement that includes you.
@@ -13,7 +13,7 @@ pub fn two(input: &str) -> crate::Result<i32> {
     let (people, matrix) = parse(input).ok_or_else(|| "failed to parse puzzle input".to_owned())?;
     all_arrangement_values(people + 1, matrix)
         .max()
-        .ok_or_else(|| "no people in input".into())
+        .ok_or_else(|| crate::Error::invalid_input("no people in input"))
 }
 
 type HappinessMatrix = HashMap<(usize, usize), i32>;
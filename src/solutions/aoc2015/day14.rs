@@ -11,7 +11,7 @@ pub fn one(input: &str) -> crate::Result<usize> {
     parse(input)
         .map(|r| distance_after_time(r, 2503))
         .max()
-        .ok_or_else(|| "no reindeer in input".into())
+        .ok_or_else(|| crate::Error::invalid_input("no reindeer in input"))
 }
 
 /// Finds the best score achieved by a reindeer after 2503 seconds.
@@ -40,7 +40,10 @@ pub fn two(input: &str) -> crate::Result<usize> {
         }
     }
 
-    scores.into_iter().max().ok_or_else(|| "no results".into())
+    scores
+        .into_iter()
+        .max()
+        .ok_or_else(|| crate::Error::no_solution("no results"))
 }
 
 type Reindeer = (usize, usize, usize);
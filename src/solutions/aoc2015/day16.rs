@@ -8,7 +8,7 @@ pub fn one(input: &str) -> crate::Result<usize> {
             return Ok(n);
         }
     }
-    Err("failed to find a matching Sue".into())
+    Err(crate::Error::no_solution("failed to find a matching Sue"))
 }
 
 /// Find the correct Sue using ranges for the appropriate values.
@@ -19,7 +19,7 @@ pub fn two(input: &str) -> crate::Result<usize> {
             return Ok(n);
         }
     }
-    Err("failed to find a matching Sue".into())
+    Err(crate::Error::no_solution("failed to find a matching Sue"))
 }
 
 type Sue<'a> = (usize, [(&'a str, usize); 3]);
@@ -31,7 +31,7 @@ pub fn two(input: &str) -> crate::Result<usize> {
 type Rules<'a> = Vec<(&'a str, &'a str)>;
 
 /// Reads the expansion rules and target molecule from puzzle input.
-fn parse(input: &str) -> Result<(Rules, &str), String> {
+fn parse(input: &str) -> crate::Result<(Rules, &str)> {
     let mut result = vec![];
 
     for line in input.lines() {
@@ -45,7 +45,9 @@ fn parse(input: &str) -> Result<(Rules, &str), String> {
         }
     }
 
-    Err("did not find final line in input".into())
+    Err(crate::Error::invalid_input(
+        "did not find final line in input",
+    ))
 }
 
 /// Builds a "structural representation" of a molecule by replacing content atoms with a `.`,
@@ -78,7 +78,7 @@ const R: [Stat; 6] = [
 ];
 
 /// Parses the puzzle input into the boss' stats.
-fn parse(input: &str) -> Result<Stat, String> {
+fn parse(input: &str) -> crate::Result<Stat> {
     let tokens: Vec<_> = input
         .lines()
         .filter_map(|line| line.split(' ').last())
@@ -87,6 +87,6 @@ fn parse(input: &str) -> Result<Stat, String> {
     if let &[hp, atk, def] = tokens.as_slice() {
         Ok((hp, atk, def))
     } else {
-        Err("failed to parse input".into())
+        Err(crate::Error::parse("failed to parse input"))
     }
 }
@@ -120,7 +120,7 @@ struct State {
 }
 
 /// Parses the puzzle input into a valid initial battle state.
-fn parse(input: &str) -> Result<State, String> {
+fn parse(input: &str) -> crate::Result<State> {
     let data: Vec<_> = input
         .lines()
         .filter_map(|line| line.split_once(": "))
@@ -135,6 +135,6 @@ fn parse(input: &str) -> Result<State, String> {
             ..Default::default()
         })
     } else {
-        Err("failed to parse puzzle input".into())
+        Err(crate::Error::parse("failed to parse puzzle input"))
     }
 }
@@ -28,7 +28,7 @@ fn find_quantum_entanglement(weights: &[usize], groups: usize) -> crate::Result<
     groups_of_size(group_weight, best_size, weights)
         .map(|g| g.iter().copied().reduce(|a, b| a * b).unwrap())
         .min()
-        .ok_or_else(|| "no quantum configuration found".into())
+        .ok_or_else(|| crate::Error::no_solution("no quantum configuration found"))
 }
 
 /// Returns all possible combinations from `weights` of the given `size` that sum up to `weight`.
@@ -44,7 +44,7 @@ fn to_index((row, col): (usize, usize)) -> usize {
 }
 
 /// Parses the puzzle input into a row and column
-fn parse(input: &str) -> Result<(usize, usize), String> {
+fn parse(input: &str) -> crate::Result<(usize, usize)> {
     fn get(items: &[&str], index: usize) -> Option<usize> {
         items.get(index).and_then(|v| v.parse().ok())
     }
@@ -57,6 +57,6 @@ fn parse(input: &str) -> Result<(usize, usize), String> {
     if let (Some(a), Some(b)) = (get(&numbers, 0), get(&numbers, 1)) {
         Ok((a, b))
     } else {
-        Err("failed to parse puzzle input".into())
+        Err(crate::Error::parse("failed to parse puzzle input"))
     }
 }
@@ -31,7 +31,7 @@ pub fn two(input: &str) -> crate::Result<i32> {
         }
     }
 
-    Err("did not find duplicate position".into())
+    Err(crate::Error::no_solution("did not find duplicate position"))
 }
 
 /// Rotates a unit vector 90 degrees to either left or the right.
@@ -1,19 +1,22 @@
-use std::collections::HashMap;
+use crate::common::{Addressing, Grid};
 
 /// Follow the instruction on the simple keypad, return the resulting code.
 pub fn one(input: &str) -> crate::Result<String> {
-    let map = build_map(&["123", "456", "789"]);
-    Ok(walk(input, (1, 1), map))
+    // Moves off the edge of the keypad keep the finger on the edge.
+    let keypad = Grid::from_iters(["123", "456", "789"].iter().map(|row| row.bytes()));
+    Ok(walk(input, (1, 1), &keypad, Addressing::Clamp))
 }
 
 /// Follow the instruction on the advanced keypad, return the resulting code.
 pub fn two(input: &str) -> crate::Result<String> {
-    let map = build_map(&["  1  ", " 234 ", "56789", " ABC ", "  D  "]);
-    Ok(walk(input, (2, 2), map))
+    let rows = ["  1  ", " 234 ", "56789", " ABC ", "  D  "];
+    let keypad = Grid::from_iters(rows.iter().map(|row| row.bytes()));
+    Ok(walk(input, (0, 2), &keypad, Addressing::Bounded))
 }
 
-/// Follows the keypad instructions, and returns the resulting code.
-fn walk(input: &str, mut pos: (i32, i32), map: HashMap<(i32, i32), char>) -> String {
+/// Follows the keypad instructions, and returns the resulting code. Moves that would end up off
+/// the keypad (as given by `addressing`), or on a blank, are ignored.
+fn walk(input: &str, mut pos: (i32, i32), keypad: &Grid<u8>, addressing: Addressing) -> String {
     let mut output = String::new();
     for line in input.lines() {
         for c in line.chars() {
@@ -24,20 +27,11 @@ fn walk(input: &str, mut pos: (i32, i32), map: HashMap<(i32, i32), char>) -> Str
                 'D' => (pos.0, pos.1 + 1),
                 _ => pos,
             };
-            if map.contains_key(&p) {
+            if let Some(p) = keypad.resolve(p, addressing).filter(|&p| keypad[p] != b' ') {
                 pos = p;
             }
         }
-        output.push(map[&pos]);
+        output.push(char::from(keypad[pos]));
     }
     output
 }
-
-/// Builds a map compatible with `walk`. `x` and `y` are the top left corner.
-fn build_map(cs: &[&str]) -> HashMap<(i32, i32), char> {
-    HashMap::from_iter((0..cs.len()).flat_map(|y| {
-        cs[y]
-            .char_indices()
-            .filter_map(move |(x, c)| (c != ' ').then_some(((x as i32, y as i32), c)))
-    }))
-}
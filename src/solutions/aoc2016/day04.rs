@@ -34,7 +34,7 @@ pub fn two(input: &str) -> crate::Result<i32> {
 
             (shifted.contains("north") && shifted.contains("pole")).then_some(id)
         })
-        .ok_or_else(|| "failed to find room".into())
+        .ok_or_else(|| crate::Error::no_solution("failed to find room"))
 }
 
 /// Parses each line of puzzle input into a triplet of the relevant information: the sector name,
@@ -31,7 +31,7 @@ pub fn two(input: &str) -> crate::Result<String> {
             }
         }
     }
-    Err("ran out of hashes".into())
+    Err(crate::Error::no_solution("ran out of hashes"))
 }
 
 /// An iterator that, for a given seed, calculates `md5(seed + i)`, where i as a number starting at
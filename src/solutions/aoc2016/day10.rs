@@ -10,7 +10,7 @@ pub fn one(input: &str) -> crate::Result<usize> {
 
     match pair {
         Some((id, _)) => Ok(id),
-        None => Err("no matching bot found".into()),
+        None => Err(crate::Error::no_solution("no matching bot found")),
     }
 }
 
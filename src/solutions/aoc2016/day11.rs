@@ -71,7 +71,7 @@ fn least_steps_to_move(input: &str, include_extras: bool) -> crate::Result<usize
 
     match prev.get(&final_key) {
         Some(&steps) => Ok(steps),
-        None => Err("no end state found".into()),
+        None => Err(crate::Error::no_solution("no end state found")),
     }
 }
 
@@ -40,7 +40,7 @@ pub fn one(input: &str) -> crate::Result<String> {
         }
     }
 
-    shortest.ok_or_else(|| "no path found".into())
+    shortest.ok_or_else(|| crate::Error::no_solution("no path found"))
 }
 
 /// Find the length of the longest possible path to the vault.
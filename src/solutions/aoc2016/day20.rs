@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 /// Find the first allowed IP address.
 pub fn one(input: &str) -> crate::Result<u32> {
     let mut ranges = parse(input);
-    find_next_allowed(&mut ranges).ok_or_else(|| "no result".into())
+    find_next_allowed(&mut ranges).ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Counts the number of allowed IP addresses.
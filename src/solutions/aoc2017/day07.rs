@@ -23,7 +23,7 @@ pub fn one(input: &str) -> crate::Result<&str> {
 
     top.into_iter()
         .next()
-        .ok_or_else(|| "no bottom disc found".into())
+        .ok_or_else(|| crate::Error::no_solution("no bottom disc found"))
 }
 
 /// Find the single incorrect value in the tree and find what it should be corrected to. A value
@@ -37,7 +37,7 @@ pub fn two(input: &str) -> crate::Result<usize> {
 
     match deepest_unbalanced {
         Some((target, current, offender)) => Ok(tree[offender].0 + target - current),
-        None => Err("no_result".into()),
+        None => Err(crate::Error::no_solution("no_result")),
     }
 }
 
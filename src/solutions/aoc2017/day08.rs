@@ -9,7 +9,7 @@ pub fn one(input: &str) -> crate::Result<i32> {
     registers
         .into_values()
         .max()
-        .ok_or_else(|| "no result".into())
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Find the highest value ever reached by any register during execution.
@@ -20,7 +20,7 @@ pub fn two(input: &str) -> crate::Result<i32> {
         line.apply(&mut registers);
         highest = highest.max(registers.values().max().copied());
     }
-    highest.ok_or_else(|| "no result".into())
+    highest.ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// A single instruction, comprised of an action and a precondition.
@@ -2,7 +2,7 @@
 pub fn one(input: &str) -> crate::Result<i32> {
     match distances(input).last() {
         Some(d) => Ok(d),
-        None => Err("no steps taken".into()),
+        None => Err(crate::Error::no_solution("no steps taken")),
     }
 }
 
@@ -10,7 +10,7 @@ pub fn one(input: &str) -> crate::Result<i32> {
 pub fn two(input: &str) -> crate::Result<i32> {
     match distances(input).max() {
         Some(d) => Ok(d),
-        None => Err("no steps taken".into()),
+        None => Err(crate::Error::no_solution("no steps taken")),
     }
 }
 
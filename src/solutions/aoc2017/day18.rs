@@ -4,7 +4,7 @@ pub fn one(input: &str) -> crate::Result<i64> {
     let output = p.run_until_blocked(&[]);
     match output.last() {
         Some(&v) => Ok(v),
-        None => Err("no result".into()),
+        None => Err(crate::Error::no_solution("no result")),
     }
 }
 
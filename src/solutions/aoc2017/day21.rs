@@ -75,7 +75,7 @@ impl Tile {
                     .flat_map(|t| rules[t].iter().copied())
                     .collect())
             }
-            _ => Err("didn't start with a 3x3 tile".into()),
+            _ => Err(crate::Error::invalid_input("didn't start with a 3x3 tile")),
         }
     }
 
@@ -157,7 +157,7 @@ fn build_rules(input: &str) -> crate::Result<Rules> {
                     rules.entry(prev).or_insert_with(|| next.clone());
                 }
             }
-            None => return Err("invalid rule in input".into()),
+            None => return Err(crate::Error::parse("invalid rule in input")),
         }
     }
     Ok(rules)
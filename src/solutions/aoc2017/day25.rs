@@ -4,7 +4,7 @@ use std::collections::HashSet;
 /// checksum" (number of 1 values of the tape after execution).
 pub fn one(input: &str) -> crate::Result<usize> {
     parse(input)
-        .ok_or_else(|| "failed parse".into())
+        .ok_or_else(|| crate::Error::parse("failed parse"))
         .map(|m| m.get_checksum())
 }
 
@@ -37,5 +37,5 @@ pub fn two(input: &str) -> crate::Result<String> {
         }
     }
 
-    Err("no match found".into())
+    Err(crate::Error::no_solution("no match found"))
 }
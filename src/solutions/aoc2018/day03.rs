@@ -22,7 +22,9 @@ pub fn two(input: &str) -> crate::Result<usize> {
         }
     }
 
-    Err("failed to find nonoverlapping claim".into())
+    Err(crate::Error::no_solution(
+        "failed to find nonoverlapping claim",
+    ))
 }
 
 /// A claim from the puzzle input.
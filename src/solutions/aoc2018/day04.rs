@@ -6,7 +6,7 @@ pub fn one(input: &str) -> crate::Result<usize> {
         .into_iter()
         .max_by_key(|(_, sleeps)| sleeps.iter().map(|sleep| sleep.1 - sleep.0).sum::<usize>())
         .map(|(id, sleeps)| id * sleep_stack(&sleeps).0)
-        .ok_or_else(|| "failed to find sleepiest guard".into())
+        .ok_or_else(|| crate::Error::no_solution("failed to find sleepiest guard"))
 }
 
 /// Find the guard that is asleep most often on the same minute, and calculate a checksum
@@ -15,7 +15,7 @@ pub fn two(input: &str) -> crate::Result<usize> {
     let (id, sleeps) = parse(input)
         .into_iter()
         .max_by_key(|(_, sleeps)| sleep_stack(sleeps).1)
-        .ok_or("failed to find most consistently sleep guard")?;
+        .ok_or_else(|| crate::Error::no_solution("failed to find most consistently sleep guard"))?;
 
     Ok(id * sleep_stack(&sleeps).0)
 }
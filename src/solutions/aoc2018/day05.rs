@@ -8,7 +8,7 @@ pub fn two(input: &str) -> crate::Result<usize> {
     (b'a'..=b'z')
         .map(|c| collapsed_length(input, Some(c)))
         .min()
-        .ok_or_else(|| "no shortest sequence found".into())
+        .ok_or_else(|| crate::Error::no_solution("no shortest sequence found"))
 }
 
 /// Calculates the length of the polymer after collapsing it and removing the provided unit.
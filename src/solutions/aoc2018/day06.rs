@@ -50,7 +50,7 @@ pub fn one(input: &str) -> crate::Result<i32> {
     counts
         .into_values()
         .max()
-        .ok_or_else(|| "no finite area".into())
+        .ok_or_else(|| crate::Error::no_solution("no finite area"))
 }
 
 /// Count the number of cells that have a total Manhattan distance to all input cells of
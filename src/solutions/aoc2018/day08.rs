@@ -1,6 +1,6 @@
 /// Find the sum of all metadata entries across the entire tree.
 pub fn one(input: &str) -> crate::Result<i32> {
-    let mut trees = vec![parse(input).ok_or("no tree parsed")?];
+    let mut trees = vec![parse(input).ok_or_else(|| crate::Error::parse("no tree parsed"))?];
     let mut result = 0;
     while let Some(tree) = trees.pop() {
         trees.extend(tree.children);
@@ -14,7 +14,10 @@ pub fn two(input: &str) -> crate::Result<i32> {
     // Because the same child node may be indexed multiple times, we effectively
     // "multiply" the value calculated from some trees. Thus, in the list of
     // trees to be processed, we carry a multiplier alongside the tree itself.
-    let mut trees = vec![(1, parse(input).ok_or("no tree parsed")?)];
+    let mut trees = vec![(
+        1,
+        parse(input).ok_or_else(|| crate::Error::parse("no tree parsed"))?,
+    )];
     let mut result = 0;
     while let Some((multiplier, tree)) = trees.pop() {
         if tree.children.is_empty() {
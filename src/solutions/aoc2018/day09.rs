@@ -3,13 +3,13 @@ use std::collections::VecDeque;
 /// Run the elf game with the given number of players and marbles, get the
 /// highest score.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let (players, marbles) = parse(input).ok_or("failed parse")?;
+    let (players, marbles) = parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
     highest_score(players, marbles)
 }
 
 /// Like [`one`], but with 100x more marbles.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let (players, marbles) = parse(input).ok_or("failed parse")?;
+    let (players, marbles) = parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
     highest_score(players, marbles * 100)
 }
 
@@ -2,7 +2,8 @@ use std::collections::HashSet;
 
 /// Find the sum of the indices of all living cells after 20 iterations.
 pub fn one(input: &str) -> crate::Result<i64> {
-    let (rules, (mut l, mut h), mut curr) = parse(input).ok_or("failed parse")?;
+    let (rules, (mut l, mut h), mut curr) =
+        parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
 
     for _ in 0..20 {
         (l, h) = (l - 2, h + 2);
@@ -14,7 +15,8 @@ pub fn one(input: &str) -> crate::Result<i64> {
 
 /// Find the sum of the indices of all living cells after fifty billion iterations.
 pub fn two(input: &str) -> crate::Result<i64> {
-    let (rules, (mut l, mut h), mut curr) = parse(input).ok_or("failed parse")?;
+    let (rules, (mut l, mut h), mut curr) =
+        parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
 
     let (mut iterations, mut diff, mut run) = (0, 0, 0);
     let mut sum = curr.iter().copied().sum::<i64>();
@@ -7,13 +7,13 @@ pub fn one(input: &str) -> crate::Result<usize> {
         OPS.iter().filter(|&&op| matches(op, *sample)).count() >= 3
     }
 
-    let (runs, _) = parse(input).ok_or("failed parse")?;
+    let (runs, _) = parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
     Ok(runs.into_iter().filter(ambiguous).count())
 }
 
 /// Identify all opcodes, run the test program, and get the output.
 pub fn two(input: &str) -> crate::Result<i32> {
-    let (samples, program) = parse(input).ok_or("failed parse")?;
+    let (samples, program) = parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
 
     let mapping = discover_opcodes(samples);
     let mut reg = [0; 4];
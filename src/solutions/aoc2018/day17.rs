@@ -1,13 +1,13 @@
 /// Count the number of tiles ever touched by water when the analysis is done.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let mut map = parse(input).ok_or("failed parse")?;
+    let mut map = parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
     map.run_water();
     Ok(map.water_tiles())
 }
 
 /// Count the number of settled water tiles when the analysis is done.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let mut map = parse(input).ok_or("failed parse")?;
+    let mut map = parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
     map.run_water();
     Ok(map.settled_tiles())
 }
@@ -111,7 +111,7 @@ fn as_counts(tiles: impl Iterator<Item = Tile>) -> (usize, usize, usize) {
 }
 
 impl std::str::FromStr for Map {
-    type Err = &'static str;
+    type Err = crate::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut map = Vec::with_capacity(2500);
@@ -121,12 +121,16 @@ impl std::str::FromStr for Map {
                     '.' => Tile::Open,
                     '|' => Tile::Tree,
                     '#' => Tile::Yard,
-                    _ => return Err("failed parse"),
+                    _ => return Err(crate::Error::parse("failed parse")),
                 })
             }
         }
 
-        let width = s.lines().next().ok_or("failed parse")?.len();
+        let width = s
+            .lines()
+            .next()
+            .ok_or_else(|| crate::Error::parse("failed parse"))?
+            .len();
         let height = s.lines().count();
 
         Ok(Self {
@@ -63,26 +63,32 @@ fn eval(op: Op, args: &[i32], reg: &mut [i32]) {
 }
 
 impl std::str::FromStr for Machine {
-    type Err = Box<dyn std::error::Error>;
+    type Err = crate::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let lines: Vec<_> = s.lines().collect();
-        let ip_register = lines[0].split_once(' ').ok_or("failed parse")?.1.parse()?;
+        let (_, register) = lines[0]
+            .split_once(' ')
+            .ok_or_else(|| crate::Error::parse("failed parse").at(1, 1))?;
+        let ip_register = register
+            .parse()
+            .map_err(|e| crate::Error::from(e).at(1, lines[0].len() - register.len() + 1))?;
         let rom = {
             let mut rom = vec![];
-            for line in &lines[1..] {
+            for (i, line) in lines.iter().enumerate().skip(1) {
+                let failed = || crate::Error::parse("failed parse").at(i + 1, 1);
                 let mut entry = Vec::with_capacity(4);
                 let mut tokens = line.split_ascii_whitespace();
-                let token = tokens.next().ok_or("failed parse")?;
+                let token = tokens.next().ok_or_else(failed)?;
                 entry.push(
                     OPS.iter()
                         .enumerate()
                         .find(|(_, &(name, _))| name == token)
-                        .ok_or("parse failed")?
+                        .ok_or_else(failed)?
                         .0 as i32,
                 );
                 entry.extend(tokens.filter_map(|v| v.parse::<i32>().ok()));
-                let entry: [i32; 4] = entry.try_into().ok().ok_or("failed parse")?;
+                let entry: [i32; 4] = entry.try_into().ok().ok_or_else(failed)?;
                 rom.push(entry);
             }
             rom
@@ -2,7 +2,10 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Find the distance to furthest-away room.
 pub fn one(input: &str) -> crate::Result<i32> {
-    distances(input).and_then(|vs| vs.max().ok_or_else(|| "no result".into()))
+    distances(input).and_then(|vs| {
+        vs.max()
+            .ok_or_else(|| crate::Error::no_solution("no result"))
+    })
 }
 
 /// Find the amount of rooms at least a distance of 1000 away.
@@ -14,7 +17,7 @@ pub fn two(input: &str) -> crate::Result<usize> {
 fn distances(input: &str) -> crate::Result<impl Iterator<Item = i32>> {
     parse(input.trim_matches(&['^', '$'][..]).as_bytes())
         .map(|ast| ast.compile().into_distances().into_values())
-        .ok_or_else(|| "parse failed".into())
+        .ok_or_else(|| crate::Error::parse("parse failed"))
 }
 
 type Doors = HashSet<((i32, i32), (i32, i32))>;
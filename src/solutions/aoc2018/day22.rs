@@ -3,14 +3,14 @@ use std::collections::{BinaryHeap, HashMap, VecDeque};
 /// Find the total risk level for the smallest rectangle including the start
 /// and target tiles.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let mut map = parse(input).ok_or("parse failed")?;
+    let mut map = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     map.extend_to(map.target);
     Ok(map.data.into_iter().flatten().map(|v| v % 3).sum())
 }
 
 /// Find the length of the shortest path to the target tile.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let mut map = parse(input).ok_or("failed parse")?;
+    let mut map = parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
     // Used to limit the search space, no point going too far past the target along either axis.
     let max_search = (map.target.0 * 2, map.target.1 * 2);
     // A queue of points to floodfill from. Stored in a heap that prioritizes the point
@@ -88,18 +88,18 @@ fn parse(input: &str) -> crate::Result<Vec<Bot>> {
     for line in input.lines() {
         let (_, pos) = line
             .split_once("=<")
-            .ok_or_else(|| "unexpected input format".to_string())?;
+            .ok_or_else(|| crate::Error::invalid_input("unexpected input format"))?;
 
         let (pos, r) = pos
             .split_once(">, r=")
-            .ok_or_else(|| "unexpected input format".to_string())?;
+            .ok_or_else(|| crate::Error::invalid_input("unexpected input format"))?;
 
         let pos = pos
             .split(',')
             .map(|n| n.parse())
             .collect::<Result<Vec<_>, _>>()?;
         let [a, b, c] = pos.as_slice() else {
-            return Err("wrong number of coordinates".into());
+            return Err(crate::Error::parse("wrong number of coordinates"));
         };
 
         bots.push(Bot {
@@ -1,6 +1,6 @@
 /// Resolve the fight, count survivors.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let (a, b) = parse(input).ok_or("failed to parse input")?;
+    let (a, b) = parse(input).ok_or_else(|| crate::Error::parse("failed to parse input"))?;
     Ok(resolve(a, b).1)
 }
 
@@ -10,7 +10,7 @@ pub fn one(input: &str) -> crate::Result<usize> {
 /// Simply does a binary search starting from a value that is high enough that it will guarantee
 /// a win.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let (a, b) = parse(input).ok_or("failed to parse puzzle input")?;
+    let (a, b) = parse(input).ok_or_else(|| crate::Error::parse("failed to parse puzzle input"))?;
 
     let mut boost = 1024;
     let mut diff = 512;
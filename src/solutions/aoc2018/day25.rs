@@ -59,6 +59,6 @@ fn parse(line: &str) -> crate::Result<Point> {
         .collect::<Result<Vec<_>, _>>()?;
     match vs.len() {
         4 => Ok((vs[0], vs[1], vs[2], vs[3])),
-        _ => Err("invalid line".into()),
+        _ => Err(crate::Error::parse("invalid line")),
     }
 }
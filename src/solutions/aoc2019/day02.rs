@@ -1,11 +1,13 @@
+use crate::common::parse_csv;
+
 /// Run the intcode program with fixed inputs.
 pub fn one(input: &str) -> crate::Result<i32> {
-    run(&mut parse(input)?, 12, 2)
+    run(&mut parse_csv(input)?, 12, 2)
 }
 
 /// Find the inputs to the intcode program that produce a specific output.
 pub fn two(input: &str) -> crate::Result<i32> {
-    let program = parse(input)?;
+    let program = parse_csv::<i32>(input)?;
 
     for noun in 0..100 {
         for verb in 0..100 {
@@ -15,7 +17,7 @@ pub fn two(input: &str) -> crate::Result<i32> {
         }
     }
 
-    Err("no successful run".into())
+    Err(crate::Error::no_solution("no successful run"))
 }
 
 /// Runs an intcode program, and returns the value in the 0th cell.
@@ -42,7 +44,9 @@ fn run(program: &mut [i32], noun: i32, verb: i32) -> crate::Result<i32> {
 /// and stores the result back in the program, as per the rules of the intcode computer.
 fn apply(program: &mut [i32], pointer: usize, f: fn(i32, i32) -> i32) -> crate::Result<()> {
     fn resolve(program: &mut [i32], index: usize) -> crate::Result<&mut i32> {
-        let index = *program.get(index).ok_or("not enough operands")?;
+        let index = *program
+            .get(index)
+            .ok_or_else(|| crate::Error::invalid_input("not enough operands"))?;
         let index =
             usize::try_from(index).map_err(|_| format!("invalid indirect pointer {index}"))?;
         Ok(program
@@ -57,8 +61,3 @@ fn apply(program: &mut [i32], pointer: usize, f: fn(i32, i32) -> i32) -> crate::
     *target = f(a, b);
     Ok(())
 }
-
-/// Parses the puzzle input.
-fn parse(input: &str) -> Result<Vec<i32>, impl std::error::Error> {
-    input.trim().split(',').map(|n| n.parse::<i32>()).collect()
-}
@@ -15,14 +15,18 @@ pub fn two(input: &str) -> crate::Result<i32> {
 fn best_intersection_by(input: &str, key: fn(((i32, i32), i32)) -> i32) -> crate::Result<i32> {
     let mut maps = input.lines().map(run_wire);
 
-    let a = maps.next().ok_or("insufficient input")??;
-    let b = maps.next().ok_or("insufficient input")??;
+    let a = maps
+        .next()
+        .ok_or_else(|| crate::Error::invalid_input("insufficient input"))??;
+    let b = maps
+        .next()
+        .ok_or_else(|| crate::Error::invalid_input("insufficient input"))??;
 
     a.into_iter()
         .filter_map(move |(p, steps)| b.get(&p).map(|v| (p, steps + v)))
         .map(key)
         .min()
-        .ok_or_else(|| "no result".into())
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Converts line instructions from the input into a map of `position reached => steps taken`
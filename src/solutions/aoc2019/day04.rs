@@ -25,7 +25,9 @@ pub fn two(input: &str) -> crate::Result<usize> {
 
 /// Counts how many passwords in a range given in the input are `valid`.
 fn count_valid_passwords(input: &str, valid: fn(&[u8]) -> bool) -> crate::Result<usize> {
-    let (a, b) = input.split_once('-').ok_or("invalid input")?;
+    let (a, b) = input
+        .split_once('-')
+        .ok_or_else(|| crate::Error::invalid_input("invalid input"))?;
     let (a, b): (i32, i32) = (a.parse()?, b.parse()?);
 
     Ok((a..=b).filter(|n| valid(n.to_string().as_bytes())).count())
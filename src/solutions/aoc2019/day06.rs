@@ -42,5 +42,5 @@ pub fn two(input: &str) -> crate::Result<usize> {
         }
     }
 
-    Err("no solution in data set".into())
+    Err(crate::Error::no_solution("no solution in data set"))
 }
@@ -1,40 +1,38 @@
-use std::collections::HashSet;
-
-use crate::common::intcode::v2::*;
+use crate::common::{intcode::v2::*, SparseGrid};
 
 /// Find how many tiles the emergency hull painting robot paints when starting on black.
 pub fn one(input: &str) -> crate::Result<usize> {
-    Ok(run_robot(input, false)?.1.len())
+    Ok(run_robot(input, false)?.len())
 }
 
 /// Find the identifier painted by the emergency hull painting robot when starting on white.
 pub fn two(input: &str) -> crate::Result<String> {
+    let panels = run_robot(input, true)?;
     Ok(crate::common::pixel_display_from_set(
-        run_robot(input, true)?.0,
+        panels
+            .iter_with_position()
+            .filter(|(_, &white)| white)
+            .map(|(p, _)| p)
+            .collect(),
     ))
 }
 
-type Points = HashSet<(i32, i32)>;
-
-/// Runs the emergency hull painting robot; returns two sets of points. The first set contains
-/// the coordinates of all tiles painted white; the second set contains the coordinates of
-/// all tiles that have been painted at least once.
-fn run_robot(input: &str, starting_panel_white: bool) -> crate::Result<(Points, Points)> {
+/// Runs the emergency hull painting robot; returns the colour of every tile that has been painted
+/// at least once (`true` for white).
+fn run_robot(input: &str, starting_panel_white: bool) -> crate::Result<SparseGrid<bool>> {
     let mut p = Program::with_capacity(input, 2000)?;
     let (mut pos, mut dir) = ((0, 0), (0, -1));
-    let mut panels = HashSet::new();
-    let mut painted = HashSet::new();
+    let mut panels = SparseGrid::new();
+    let is_white = |panels: &SparseGrid<bool>, pos| panels.get(pos) == Some(&true);
 
     if starting_panel_white {
-        panels.insert((0, 0));
+        panels.insert((0, 0), true);
     }
 
-    p.run_with([panels.contains(&pos)])?;
+    p.run_with([is_white(&panels, pos)])?;
     while !p.output.is_empty() {
-        painted.insert(pos);
         match p.output.pop_front() {
-            Some(0) => panels.remove(&pos),
-            Some(1) => panels.insert(pos),
+            Some(n @ (0 | 1)) => panels.insert(pos, n == 1),
             n => Err(format!("invalid paint instruction {n:?}"))?,
         };
 
@@ -45,8 +43,8 @@ fn run_robot(input: &str, starting_panel_white: bool) -> crate::Result<(Points,
         };
 
         pos = (pos.0 + dir.0, pos.1 + dir.1);
-        p.run_with([panels.contains(&pos)])?;
+        p.run_with([is_white(&panels, pos)])?;
     }
 
-    Ok((panels, painted))
+    Ok(panels)
 }
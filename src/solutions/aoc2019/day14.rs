@@ -67,7 +67,9 @@ fn cost(nodes: &HashMap<&str, Node>, resource: &str, product: &str, amount: usiz
 /// Parses the puzzle input into a map of resource names to their data.
 fn parse(input: &str) -> crate::Result<HashMap<&str, Node>> {
     fn entry(entry: &str) -> crate::Result<(usize, &str)> {
-        let (amount, material) = entry.split_once(' ').ok_or("invalid entry")?;
+        let (amount, material) = entry
+            .split_once(' ')
+            .ok_or_else(|| crate::Error::parse("invalid entry"))?;
         Ok((amount.parse()?, material))
     }
 
@@ -75,7 +77,9 @@ fn parse(input: &str) -> crate::Result<HashMap<&str, Node>> {
     let mut result = std::iter::once("1 NOTHING => 1 ORE")
         .chain(input.lines())
         .map(|line| {
-            let (mats, product) = line.split_once(" => ").ok_or("invalid line")?;
+            let (mats, product) = line
+                .split_once(" => ")
+                .ok_or_else(|| crate::Error::parse("invalid line"))?;
             let (count, product) = entry(product)?;
             for mat in mats.split(", ").map(entry) {
                 let (count, mat) = mat?;
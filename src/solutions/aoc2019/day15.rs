@@ -12,7 +12,9 @@ pub fn one(input: &str) -> crate::Result<usize> {
 pub fn two(input: &str) -> crate::Result<usize> {
     let (mut map, goal) = flood_fill(Program::new(input)?)?;
     recenter(&mut map, goal);
-    map.into_values().max().ok_or_else(|| "no result".into())
+    map.into_values()
+        .max()
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 type Point = (i32, i32);
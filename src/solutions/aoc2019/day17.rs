@@ -91,7 +91,7 @@ fn into_routines(mut walk_plan: String) -> crate::Result<(String, [String; 3])>
         }
     }
 
-    Err("no solution".into())
+    Err(crate::Error::no_solution("no solution"))
 }
 
 /// Construct the walk plan out of scaffold data.
@@ -126,7 +126,7 @@ fn parse(input: &str) -> crate::Result<Vec<Action>> {
             } else if line == "deal into new stack" {
                 Action::Deal
             } else {
-                Err("invalid line")?
+                Err(crate::Error::parse("invalid line"))?
             })
         })
         .collect()
@@ -8,7 +8,7 @@ pub fn one(input: &str) -> crate::Result<i32> {
             }
         }
     }
-    Err("no result".into())
+    Err(crate::Error::no_solution("no result"))
 }
 
 /// Find the product of three entries that sum to 2020.
@@ -23,5 +23,5 @@ pub fn two(input: &str) -> crate::Result<i32> {
             }
         }
     }
-    Err("no result".into())
+    Err(crate::Error::no_solution("no result"))
 }
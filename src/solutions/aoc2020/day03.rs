@@ -1,31 +1,27 @@
+use crate::common::Grid;
+
 /// Find the number of trees on the given slope.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let map: Map = input.lines().collect();
+    let map = Grid::from_input(input);
     Ok(trees_on_slope(&map, (3, 1)))
 }
 
 /// Find the product of the numbers of trees on all given slopes.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let map: Map = input.lines().collect();
+    let map = Grid::from_input(input);
     [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
         .map(|p| trees_on_slope(&map, p))
         .into_iter()
         .reduce(|a, b| a * b)
-        .ok_or_else(|| "no result".into())
-}
-
-type Map<'a> = Vec<&'a str>;
-
-/// Produces a list of all coordinates reached when jumping by `(dx, dy)` every step.
-/// `(mx, my)` is the bounds of the map. When `my` is reached, the slope terminates; when
-/// `mx` is reached, it wraps back around to 0.
-fn slope((dx, dy): (i32, i32), (mx, my): (i32, i32)) -> impl Iterator<Item = (i32, i32)> {
-    (0..(my / dy)).map(move |n| ((dx * n) % mx, dy * n))
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
-/// Counts the number of trees on a slope.
-fn trees_on_slope(map: &Map, d: (i32, i32)) -> usize {
-    slope(d, (map[0].len() as i32, map.len() as i32))
-        .filter(|&(x, y)| map[y as usize].as_bytes()[x as usize] == b'#')
+/// Counts the number of trees on a slope, jumping by `(dx, dy)` every step. The map repeats to
+/// the right, and the slope ends once it gets below the map.
+fn trees_on_slope(map: &Grid<u8>, (dx, dy): (i32, i32)) -> usize {
+    (0..)
+        .map_while(|n| map.get_tiled((dx * n, dy * n)))
+        .take_while(|&(_, tile)| tile.1 == 0)
+        .filter(|&(&cell, _)| cell == b'#')
         .count()
 }
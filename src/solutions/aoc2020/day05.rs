@@ -4,7 +4,7 @@ pub fn one(input: &str) -> crate::Result<i32> {
         .lines()
         .filter_map(seat_id)
         .max()
-        .ok_or_else(|| "no result".into())
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Find the seat Id not in the input, but for which both adjacent Ids are in the input.
@@ -14,7 +14,7 @@ pub fn two(input: &str) -> crate::Result<i32> {
     ids.windows(2)
         .find(|w| (w[1] - w[0]) == 2)
         .map(|w| w[0] + 1)
-        .ok_or_else(|| "no result".into())
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Calculates the seat Id from an input line.
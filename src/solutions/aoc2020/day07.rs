@@ -1,17 +1,17 @@
 use std::collections::HashSet;
 
 pub fn one(input: &str) -> crate::Result<usize> {
-    let graph = parse(input).ok_or("parse failed")?;
+    let graph = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     graph
         .possible_container_count("shiny gold")
-        .ok_or_else(|| "no result".into())
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 pub fn two(input: &str) -> crate::Result<usize> {
-    let graph = parse(input).ok_or("parse failed")?;
+    let graph = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     graph
         .contained_bag_count("shiny gold")
-        .ok_or_else(|| "no result".into())
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// An adjacency list-based graph describing which bags contain which, and how many.
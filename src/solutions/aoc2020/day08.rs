@@ -14,7 +14,7 @@ pub fn two(input: &str) -> crate::Result<i32> {
         .filter_map(|i| with_swapped(&code, i))
         .map(run)
         .find(|result| result.is_ok())
-        .ok_or("no result")?
+        .ok_or_else(|| crate::Error::no_solution("no result"))?
         .unwrap())
 }
 
@@ -27,7 +27,7 @@ fn run(input: &str, find_encyption_weakness: bool) -> crate::Result<i32> {
         .windows(26)
         .find(|w| !any_two_sum_to(&w[0..25], w[25]))
         .map(|w| w[25])
-        .ok_or("no result")?;
+        .ok_or_else(|| crate::Error::no_solution("no result"))?;
 
     if !find_encyption_weakness {
         return Ok(invalid_number);
@@ -42,15 +42,21 @@ fn run(input: &str, find_encyption_weakness: bool) -> crate::Result<i32> {
                 Ordering::Less => continue,
                 Ordering::Equal => {
                     let range = &data[start..=(start + n)];
-                    return Ok(range.iter().min().ok_or("empty range")?
-                        + range.iter().max().ok_or("empty range")?);
+                    return Ok(range
+                        .iter()
+                        .min()
+                        .ok_or_else(|| crate::Error::invalid_input("empty range"))?
+                        + range
+                            .iter()
+                            .max()
+                            .ok_or_else(|| crate::Error::invalid_input("empty range"))?);
                 }
                 Ordering::Greater => break,
             }
         }
     }
 
-    Err("no result".into())
+    Err(crate::Error::no_solution("no result"))
 }
 
 /// Checks if any two numbers in `data` sum to `sum`.
@@ -54,7 +54,10 @@ pub fn two(input: &str) -> crate::Result<i64> {
         }
     }
 
-    cache.last().copied().ok_or_else(|| "no result".into())
+    cache
+        .last()
+        .copied()
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Parses the puzzle list into a sorted list of joltages, including a 0 and `max + 3`
@@ -1,14 +1,14 @@
 /// Find the number of seats taken in equilibrium using the basic ruleset.
 pub fn one(input: &str) -> crate::Result<usize> {
     Ok(Map::from_input(input, false)
-        .ok_or("failed parse")?
+        .ok_or_else(|| crate::Error::parse("failed parse"))?
         .run_until_equilibrium())
 }
 
 /// Find the number of seats taken in equilibrium using the extended ruleset.
 pub fn two(input: &str) -> crate::Result<usize> {
     Ok(Map::from_input(input, true)
-        .ok_or("failed parse")?
+        .ok_or_else(|| crate::Error::parse("failed parse"))?
         .run_until_equilibrium())
 }
 
@@ -1,18 +1,18 @@
 /// Find the bus that leaves soonest after the timestamp, and calculate a checksum from it.
 pub fn one(input: &str) -> crate::Result<i64> {
-    let (timestamp, buses) = parse(input).ok_or("parse failed")?;
+    let (timestamp, buses) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     let (bus, time) = buses
         .into_iter()
         .map(|(bus, _)| (bus, bus - timestamp % bus))
         .min_by_key(|&(_, time)| time)
-        .ok_or("no result")?;
+        .ok_or_else(|| crate::Error::no_solution("no result"))?;
     Ok(bus * time)
 }
 
 /// Find the first point in time starting at which all buses leave one after another on
 /// each consecutive minute.
 pub fn two(input: &str) -> crate::Result<i64> {
-    let (_, buses) = parse(input).ok_or("parse failed")?;
+    let (_, buses) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
 
     // This is basically a big modulo constraint puzzle. We have to count up until, for each
     // bus, [(time + position in list) % busnumber] is zero. Fortunately, this can be made faster;
@@ -14,7 +14,9 @@ pub fn one(input: &str) -> crate::Result<u64> {
             and_mask = u64::from_str_radix(&mask.replace('X', "1"), 2)?;
             or_mask = u64::from_str_radix(&mask.replace('X', "0"), 2)?;
         } else if let Some(line) = line.strip_prefix("mem[") {
-            let (addr, val) = line.split_once("] = ").ok_or("invalid mem line")?;
+            let (addr, val) = line
+                .split_once("] = ")
+                .ok_or_else(|| crate::Error::parse("invalid mem line"))?;
             *values.entry(addr.parse()?).or_default() = val.parse::<u64>()? & and_mask | or_mask
         }
     }
@@ -36,7 +38,9 @@ pub fn two(input: &str) -> crate::Result<u64> {
                 .filter(|i| mask.as_bytes()[mask.len() - i - 1] == b'X')
                 .collect();
         } else if let Some(line) = line.strip_prefix("mem[") {
-            let (addr, val) = line.split_once("] = ").ok_or("invalid mem line")?;
+            let (addr, val) = line
+                .split_once("] = ")
+                .ok_or_else(|| crate::Error::parse("invalid mem line"))?;
             let mut target = addr.parse::<u64>()? | or_mask;
             let val = val.parse::<u64>()?;
             for i in 0..2usize.pow(floats.len() as u32) {
@@ -77,7 +77,9 @@ fn parse(input: &str) -> crate::Result<(Vec<Field>, Ticket, Vec<Ticket>)> {
     let mut lines = input.lines();
 
     fn range(s: &str) -> crate::Result<RangeInclusive<i32>> {
-        let (min, max) = s.split_once('-').ok_or("parse failed")?;
+        let (min, max) = s
+            .split_once('-')
+            .ok_or_else(|| crate::Error::parse("parse failed"))?;
         Ok(min.parse()?..=max.parse()?)
     }
 
@@ -91,8 +93,12 @@ fn parse(input: &str) -> crate::Result<(Vec<Field>, Ticket, Vec<Ticket>)> {
             break;
         }
 
-        let (name, ranges) = line.split_once(": ").ok_or("parse failed")?;
-        let (a, b) = ranges.split_once(" or ").ok_or("parse failed")?;
+        let (name, ranges) = line
+            .split_once(": ")
+            .ok_or_else(|| crate::Error::parse("parse failed"))?;
+        let (a, b) = ranges
+            .split_once(" or ")
+            .ok_or_else(|| crate::Error::parse("parse failed"))?;
         fields.push(Field {
             name,
             range_one: range(a)?,
@@ -101,7 +107,11 @@ fn parse(input: &str) -> crate::Result<(Vec<Field>, Ticket, Vec<Ticket>)> {
     }
 
     lines.next();
-    let own = ticket(lines.next().ok_or("unexpected end of input")?)?;
+    let own = ticket(
+        lines
+            .next()
+            .ok_or_else(|| crate::Error::invalid_input("unexpected end of input"))?,
+    )?;
     let other = lines.skip(2).map(ticket).collect::<Result<Vec<_>, _>>()?;
 
     Ok((fields, own, other))
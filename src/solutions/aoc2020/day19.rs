@@ -2,14 +2,14 @@ use std::collections::HashMap;
 
 /// Find the number of strings matching rule 0.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let (a, b, rules, lines) = parse(input).ok_or("parse failed")?;
+    let (a, b, rules, lines) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     let regex = compile_to_regex(a, b, rules, false)?;
     Ok(lines.filter(|line| regex.is_match(line)).count())
 }
 
 /// Find the number of strings matching rule 0, if rules #8 and #11 can loop.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let (a, b, rules, lines) = parse(input).ok_or("parse failed")?;
+    let (a, b, rules, lines) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     let regex = compile_to_regex(a, b, rules, true)?;
     Ok(lines.filter(|line| regex.is_match(line)).count())
 }
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::common::Grid;
+
 /// Reconstruct the map; find the product of the four corner tile IDs.
 pub fn one(input: &str) -> crate::Result<usize> {
     let map = build_map(parse(input)?);
@@ -11,85 +13,63 @@ pub fn one(input: &str) -> crate::Result<usize> {
 /// a monster).
 pub fn two(input: &str) -> crate::Result<usize> {
     let map = build_map(parse(input)?);
-    Ok(total_roughness(map))
+    Ok(total_roughness(&image(&map)))
 }
 
 type TempMap = HashMap<(i32, i32), (Tile, Transform)>;
 type Map = Vec<Vec<(Tile, Transform)>>;
 
-/// A list of points that constitute a sea monster.
-const MONSTER_OFFSETS: [(i32, i32); 15] = [
-    (0, 1),
-    (1, 2),
-    (4, 2),
-    (5, 1),
-    (6, 1),
-    (7, 2),
-    (10, 2),
-    (11, 1),
-    (12, 1),
-    (13, 2),
-    (16, 2),
-    (17, 1),
-    (18, 0),
-    (18, 1),
-    (19, 1),
+/// What a sea monster looks like; only the # are part of it.
+const MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
 ];
 
-/// Calculates the total roughness of the map; ie. the number of rough tiles (#) that aren't
-/// part of a monster.
-fn total_roughness(map: Map) -> usize {
-    let mut roughness = 0;
-    let mut monsters = 0;
-
-    for y in 0..8 * map.len() as i32 {
-        for x in 0..8 * map[0].len() as i32 {
-            if rough_at(&map, (x, y)) {
-                roughness += 1;
-            }
-            if monster_at(&map, (x, y)) {
-                monsters += 1;
-            }
-        }
-    }
+/// Joins the tiles of the map into a single image, leaving out their borders.
+fn image(map: &Map) -> Grid<'static, u8> {
+    let inner: Vec<Vec<_>> = map
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|(tile, t)| {
+                    let tile = Grid::from_fn(10, 10, |(x, y)| {
+                        tile.data[t.apply1d((x as usize, y as usize))]
+                    });
+                    tile.crop((1, 1), 8, 8)
+                })
+                .collect()
+        })
+        .collect();
 
-    roughness - monsters * MONSTER_OFFSETS.len()
+    Grid::from_fn(8 * map[0].len(), 8 * map.len(), |(x, y)| {
+        inner[y as usize / 8][x as usize / 8][(x % 8, y % 8)]
+    })
 }
 
-/// Checks if the given tile is "rough" (the character '#'). Coordinates beyond the map never
-/// rough.
-fn rough_at(map: &Map, (x, y): (i32, i32)) -> bool {
-    if x < 0 || y < 0 {
-        return false;
-    }
+/// Calculates the total roughness of the image; ie. the number of rough tiles (#) that aren't
+/// part of a monster. As the image may be rotated or flipped, monsters can be in any orientation.
+fn total_roughness(image: &Grid<u8>) -> usize {
+    let monster = Grid::from_iters(MONSTER.iter().map(|line| line.bytes()));
+    let orientations = monster.orientations();
 
-    let (tile_x, tile_y) = ((x / 8) as usize, (y / 8) as usize);
-
-    if let Some((tile, t)) = map.get(tile_y).and_then(|vs| vs.get(tile_x)) {
-        let c = *tile
-            .data
-            .get(t.apply1d(((1 + x % 8) as usize, (1 + y % 8) as usize)))
-            .unwrap_or(&b' ');
-        c == b'#'
-    } else {
-        false
-    }
-}
-
-/// Checks if there is a monster at the given coordinate. To be a monster, it must match the
-/// pattern given by [`MONSTER_OFFSETS`].
-fn monster_at(map: &Map, (x, y): (i32, i32)) -> bool {
-    for t in Transform::all() {
-        if MONSTER_OFFSETS
-            .map(|p| t.apply(p))
-            .map(|(dx, dy)| (x + dx, y + dy))
-            .iter()
-            .all(|&p| rough_at(map, p))
-        {
-            return true;
+    let monster_at = |(x, y): (i32, i32), monster: &Grid<u8>| {
+        let (width, height) = (monster.width(), monster.height());
+        if x as usize + width > image.width() || y as usize + height > image.height() {
+            return false;
         }
-    }
-    false
+
+        let view = image.view((x, y), width, height);
+        monster
+            .coordinates()
+            .all(|p| monster[p] != b'#' || view[p] == b'#')
+    };
+    let monsters = image
+        .coordinates()
+        .filter(|&p| orientations.iter().any(|monster| monster_at(p, monster)))
+        .count();
+
+    image.count(|&c| c == b'#') - monsters * monster.count(|&c| c == b'#')
 }
 
 /// Reconstructs the map from a list of tiles.
@@ -180,8 +160,12 @@ fn parse(input: &str) -> crate::Result<Vec<Tile>> {
             data: vec![],
         };
         for _ in 0..10 {
-            tile.data
-                .extend(lines.next().ok_or("malformed input")?.bytes())
+            tile.data.extend(
+                lines
+                    .next()
+                    .ok_or_else(|| crate::Error::invalid_input("malformed input"))?
+                    .bytes(),
+            )
         }
         lines.next();
         tiles.push(tile);
@@ -245,14 +229,4 @@ impl Transform {
         if v { p = (p.0, 9 - p.1); }
         p.1 * 10 + p.0
     }
-
-    /// Applies this transformation to the given point.
-    #[rustfmt::skip]
-    fn apply(&self, mut p: (i32, i32)) -> (i32, i32) {
-        let &Transform(r, h, v) = self;
-        if r { p = (-p.1, p.0); }
-        if h { p = (-p.0, p.1); }
-        if v { p = (p.0, -p.1); }
-        p
-    }
 }
@@ -2,7 +2,8 @@ use std::collections::HashMap;
 
 /// Counts the number of safe ingredients (without any allergens) in them across all recipes.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let (allergens, recipes) = get_allergens_and_recipes(input).ok_or("parse failed")?;
+    let (allergens, recipes) =
+        get_allergens_and_recipes(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     Ok(recipes
         .into_iter()
         .flatten()
@@ -13,7 +14,8 @@ pub fn one(input: &str) -> crate::Result<usize> {
 /// Finds the canonical list of allergens (the actual ingredients sorted by the alphabetical
 /// order of the allergen they contain).
 pub fn two(input: &str) -> crate::Result<String> {
-    let (allergens, _) = get_allergens_and_recipes(input).ok_or("parse failed")?;
+    let (allergens, _) =
+        get_allergens_and_recipes(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     let mut allergens: Vec<_> = allergens.into_iter().collect();
     allergens.sort_by_key(|(k, _)| *k);
     let allergens: Vec<_> = allergens.into_iter().map(|(_, v)| v).collect();
@@ -1,7 +1,8 @@
 /// Find the arrangement of the 9 input cups after 100 steps of the cup game; starting at (but
 /// not including) cup #1.
 pub fn one(input: &str) -> crate::Result<String> {
-    let cups = run_cups(input.trim(), 9, 100).ok_or("failed cup game")?;
+    let cups = run_cups(input.trim(), 9, 100)
+        .ok_or_else(|| crate::Error::no_solution("failed cup game"))?;
 
     let mut result = String::new();
     let mut pointer = 0;
@@ -16,7 +17,8 @@ pub fn one(input: &str) -> crate::Result<String> {
 /// Run the cup game for 1 million cups and 10 million steps, then find the product of the labels
 /// of the two cups right after cup #1.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let cups = run_cups(input.trim(), 1_000_000, 10_000_000).ok_or("failed cup game")?;
+    let cups = run_cups(input.trim(), 1_000_000, 10_000_000)
+        .ok_or_else(|| crate::Error::no_solution("failed cup game"))?;
     Ok((cups[0] + 1) * (cups[cups[0]] + 1))
 }
 
@@ -1,8 +1,14 @@
 /// Find the encryption key for the given pair of public keys.
 pub fn one(input: &str) -> crate::Result<i32> {
     let mut lines = input.lines();
-    let card_key = lines.next().ok_or("insufficient input")?.parse()?;
-    let door_key = lines.next().ok_or("insufficient input")?.parse()?;
+    let card_key = lines
+        .next()
+        .ok_or_else(|| crate::Error::invalid_input("insufficient input"))?
+        .parse()?;
+    let door_key = lines
+        .next()
+        .ok_or_else(|| crate::Error::invalid_input("insufficient input"))?
+        .parse()?;
 
     Ok(modular_exponentiation(
         door_key,
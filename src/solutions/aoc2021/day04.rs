@@ -34,7 +34,9 @@ impl Bingo {
     /// Parses `input` into a `Bingo` game.
     fn from_input(input: &str) -> crate::Result<Bingo> {
         let mut tokens = input.split_whitespace();
-        let draws = tokens.next().ok_or("no input")?;
+        let draws = tokens
+            .next()
+            .ok_or_else(|| crate::Error::invalid_input("no input"))?;
 
         Ok(Bingo {
             draws: draws
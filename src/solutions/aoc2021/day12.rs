@@ -23,13 +23,15 @@ impl Graph<'_> {
         fn index<T: PartialEq>(v: &[T], item: &T) -> crate::Result<usize> {
             v.iter()
                 .position(|t| t == item)
-                .ok_or_else(|| "parse failed".into())
+                .ok_or_else(|| crate::Error::parse("parse failed"))
         }
 
         let mut nodes = vec![];
         let mut edges = vec![];
         for line in input.lines() {
-            let (a, b) = line.split_once('-').ok_or("parse failed")?;
+            let (a, b) = line
+                .split_once('-')
+                .ok_or_else(|| crate::Error::parse("parse failed"))?;
             for node in [a, b] {
                 if !nodes.contains(&node) {
                     nodes.push(node);
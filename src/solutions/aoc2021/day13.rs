@@ -43,10 +43,14 @@ fn parse(input: &str) -> crate::Result<(Points, Folds)> {
 
     for line in input.lines() {
         if line.contains(',') {
-            let (x, y) = line.split_once(',').ok_or("unexpected input")?;
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| crate::Error::invalid_input("unexpected input"))?;
             points.insert((x.parse()?, y.parse()?));
         } else if line.contains('=') {
-            let (pre, coord) = line.split_once('=').ok_or("unexpected input")?;
+            let (pre, coord) = line
+                .split_once('=')
+                .ok_or_else(|| crate::Error::invalid_input("unexpected input"))?;
             folds.push((pre.ends_with('y'), coord.parse()?));
         }
     }
@@ -4,14 +4,14 @@ use std::collections::HashMap;
 /// common byte.
 pub fn one(input: &str) -> crate::Result<i64> {
     let (polymer, pairs) = parse(input)?;
-    score_after_steps(polymer, &pairs, 10).ok_or_else(|| "no result".into())
+    score_after_steps(polymer, &pairs, 10).ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Expand the polymer 40 times, get the difference in occurences of the most common and the least
 /// common byte.
 pub fn two(input: &str) -> crate::Result<i64> {
     let (polymer, pairs) = parse(input)?;
-    score_after_steps(polymer, &pairs, 40).ok_or_else(|| "no result".into())
+    score_after_steps(polymer, &pairs, 40).ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 type Rules = HashMap<(u8, u8), u8>;
@@ -54,12 +54,17 @@ fn score_after_steps(polymer: &[u8], rules: &Rules, steps: usize) -> Option<i64>
 /// Parses the puzzle input into an input polymer and an expansion rule map.
 fn parse(input: &str) -> crate::Result<(&[u8], Rules)> {
     let mut lines = input.lines();
-    let polymer = lines.next().ok_or("insufficient input")?.as_bytes();
+    let polymer = lines
+        .next()
+        .ok_or_else(|| crate::Error::invalid_input("insufficient input"))?
+        .as_bytes();
     let mut pairs = HashMap::new();
 
     for line in lines {
         if line.contains(" -> ") {
-            let (pair, new) = line.split_once(" -> ").ok_or("unexpected input")?;
+            let (pair, new) = line
+                .split_once(" -> ")
+                .ok_or_else(|| crate::Error::invalid_input("unexpected input"))?;
             pairs.insert((pair.as_bytes()[0], pair.as_bytes()[1]), new.as_bytes()[0]);
         }
     }
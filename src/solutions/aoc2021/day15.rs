@@ -3,12 +3,12 @@ use std::collections::BinaryHeap;
 
 /// Find the lowest risk achievable when crossing the grid.
 pub fn one(input: &str) -> crate::Result<i32> {
-    find_risk(parse(input)).ok_or_else(|| "no result".into())
+    find_risk(parse(input)).ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Find the lowest risk achievable when crossing the fivefold expanded grid.
 pub fn two(input: &str) -> crate::Result<i32> {
-    find_risk(parse_large(input)).ok_or_else(|| "no result".into())
+    find_risk(parse_large(input)).ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Calculates the lowest possible risk as per the puzzle rules.
@@ -132,10 +132,10 @@ fn parse_bits_string(s: &str) -> crate::Result<Packet> {
 
     let mut i = 0;
     Ok(recursive_parse(s, &mut i, Some(1))
-        .ok_or("parse failed")?
+        .ok_or_else(|| crate::Error::parse("parse failed"))?
         .into_iter()
         .next()
-        .ok_or("parse failed")?)
+        .ok_or_else(|| crate::Error::parse("parse failed"))?)
 }
 
 /// Expands the hexadecimal string into a BITS string (containing only 0s and 1s).
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 /// Find the biggest height you could conceivably achieve with a shot, and still land in the target
 /// area.
 pub fn one(input: &str) -> crate::Result<i32> {
-    let (_, ys) = parse(input).ok_or("parse failed")?;
+    let (_, ys) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
 
     // We can completely ignore the horizontal component of the velocity, because we know that we
     // can always find a speed that gives us infinite steps (by reaching x velocity 0 within the
@@ -26,7 +26,7 @@ pub fn one(input: &str) -> crate::Result<i32> {
 
 /// Count the number of initial velocities that would land within the target area.
 pub fn two(input: &str) -> crate::Result<i32> {
-    let (xs, ys) = parse(input).ok_or("parse failed")?;
+    let (xs, ys) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
 
     // Build a map of [(step range) => (size of set of initial x velocities with that range)]
     let mut valid_xs = HashMap::new();
@@ -4,7 +4,7 @@ pub fn one(input: &str) -> crate::Result<i32> {
         .lines()
         .map(Expr::from_input)
         .reduce(|a, b| Expr::add(&a, &b))
-        .ok_or("no input")?
+        .ok_or_else(|| crate::Error::invalid_input("no input"))?
         .magnitude())
 }
 
@@ -2,17 +2,19 @@ use std::collections::HashSet;
 
 /// Find the number of beacons.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let (map, _) = combine_into_one_map(parse(input).ok_or("parse failed")?);
+    let (map, _) =
+        combine_into_one_map(parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?);
     Ok(map.len())
 }
 
 /// Find the manhattan distance bewteen the two furthest-away scanners.
 pub fn two(input: &str) -> crate::Result<i32> {
-    let (_, scanners) = combine_into_one_map(parse(input).ok_or("parse failed")?);
+    let (_, scanners) =
+        combine_into_one_map(parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?);
     product(scanners.iter(), scanners.iter())
         .map(|((ax, ay, az), (bx, by, bz))| (ax - bx).abs() + (ay - by).abs() + (az - bz).abs())
         .max()
-        .ok_or_else(|| "no result".into())
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Given the parsed puzzle input, returns the full combined map, as well as the positions of all
@@ -3,7 +3,8 @@ use std::collections::HashMap;
 /// Play the game using a deterministic d100 (as described in the puzzle). Find the score of the
 /// losing player multiplied by the amount of rolls done.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let (mut p1, mut p2) = parse(input).ok_or("failed to parse input")?;
+    let (mut p1, mut p2) =
+        parse(input).ok_or_else(|| crate::Error::parse("failed to parse input"))?;
     let (mut s1, mut s2) = (0, 0);
     let (mut die, mut rolls) = (0, 0);
     let mut player2next = false;
@@ -34,8 +35,10 @@ pub fn one(input: &str) -> crate::Result<usize> {
 /// Play the game using a d3; count how many possible games each player can win, and get the higher
 /// of those two numbers.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let mut universes =
-        HashMap::from([(State::new(parse(input).ok_or("failed to parse input")?), 1)]);
+    let mut universes = HashMap::from([(
+        State::new(parse(input).ok_or_else(|| crate::Error::parse("failed to parse input"))?),
+        1,
+    )]);
 
     let (mut w1, mut w2) = (0, 0);
     while !universes.is_empty() {
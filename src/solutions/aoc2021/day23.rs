@@ -1,4 +1,4 @@
-use crate::common::astar::{self, AStarNode};
+use crate::common::astar;
 
 /// Sort amphipods as efficiently as possible, return used energy.
 pub fn one(input: &str) -> crate::Result<i32> {
@@ -22,14 +22,16 @@ struct State<const COL_LEN: usize> {
     cols: [heapless::Vec<u8, COL_LEN>; 4],
 }
 
-impl<const COL_LEN: usize> AStarNode for State<COL_LEN> {
+impl<'a, const COL_LEN: usize> astar::Node<'a> for State<COL_LEN> {
     type Cost = i32;
     type Env = ();
 
     /// Produces all possible next states.
-    fn next(&self, _: &Self::Env) -> Box<dyn Iterator<Item = (Self, Self::Cost)> + '_> {
-        Box::new(self.moves().map(move |(m, cost)| {
-            let mut next = self.clone();
+    fn next(&self, _: &'a Self::Env) -> Box<dyn Iterator<Item = (Self, Self::Cost)> + 'a> {
+        let moves: Vec<_> = self.moves().collect();
+        let state = self.clone();
+        Box::new(moves.into_iter().map(move |(m, cost)| {
+            let mut next = state.clone();
             match (m.0, m.1) {
                 (Index::Column(s), Index::Column(t)) => {
                     let val = next.cols[s].pop().unwrap();
@@ -52,7 +54,7 @@ impl<const COL_LEN: usize> AStarNode for State<COL_LEN> {
 
     /// Returns what it would cost if every missing amphipod was 1 step away
     /// from home.
-    fn heuristic(&self, _: &Self::Env) -> Self::Cost {
+    fn heuristic(&self, _: &'a Self::Env) -> Self::Cost {
         (0..4)
             .map(|n| {
                 let correct = self.cols[n].iter().take_while(|&&b| b == (n as u8 + b'A'));
@@ -62,7 +64,7 @@ impl<const COL_LEN: usize> AStarNode for State<COL_LEN> {
     }
 
     /// Checks if all amphipods are home.
-    fn done(&self, _: &Self::Env) -> bool {
+    fn done(&self, _: &'a Self::Env) -> bool {
         self.cols
             .iter()
             .enumerate()
@@ -1,6 +1,8 @@
 /// Find the total calories carried by the top elf.
 pub fn one(input: &str) -> crate::Result<i32> {
-    parse(input).max().ok_or_else(|| "no result".into())
+    parse(input)
+        .max()
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Find the total calories carried by the top three elves.
@@ -25,7 +25,7 @@ type Command = (i32, usize, usize);
 /// Executes all commands given in the puzzle input and returns a string built from the top
 /// character of each stack. `move_fn` is called to perform the actual stack manipulation.
 fn run_crates(input: &str, move_fn: MoveFn) -> crate::Result<String> {
-    let (mut stacks, commands) = parse(input).ok_or("parse failed")?;
+    let (mut stacks, commands) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
 
     for (n, from, to) in commands {
         let (a, b) = stacks.split_at_mut(from.max(to));
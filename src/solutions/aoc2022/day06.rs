@@ -20,7 +20,7 @@ fn start_marker(input: &str, len: usize) -> crate::Result<usize> {
             cs.dedup();
             cs.len() == len
         })
-        .ok_or("no start marker")?;
+        .ok_or_else(|| crate::Error::invalid_input("no start marker"))?;
 
     Ok(i + len)
 }
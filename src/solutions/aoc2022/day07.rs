@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 /// Find the sum of the sizes of all directories below a certain size.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let dirs = parse(input).ok_or("parse failed")?;
+    let dirs = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     let mut cache = HashMap::new();
 
     Ok(dirs
@@ -17,7 +17,7 @@ pub fn two(input: &str) -> crate::Result<usize> {
     const TOTAL_SIZE: usize = 70000000;
     const REQUIRED: usize = 30000000;
 
-    let dirs = parse(input).ok_or("parse failed")?;
+    let dirs = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     let mut cache = HashMap::new();
     let to_free = REQUIRED - (TOTAL_SIZE - get_size("", &dirs, &mut cache));
 
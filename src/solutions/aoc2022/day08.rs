@@ -16,7 +16,7 @@ pub fn two(input: &str) -> crate::Result<i32> {
         .flat_map(|y| (0..map[y].len()).map(move |x| (x, y)))
         .map(|p| scenic_score(&map, p))
         .max()
-        .ok_or_else(|| "no result".into())
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 type Map = Vec<Vec<u8>>;
@@ -49,7 +49,7 @@ fn shortest_path(data: Data, start: Point, end: Option<(i32, i32)>) -> crate::Re
         }
     }
 
-    Err("no path found".into())
+    Err(crate::Error::no_solution("no path found"))
 }
 
 /// Heightmap data.
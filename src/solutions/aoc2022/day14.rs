@@ -16,7 +16,7 @@ fn find_settled_count(input: &str, condition: fn(Point, i32) -> bool) -> crate::
     (0..)
         .map(move |_| drop_sand(&mut map, end_line))
         .position(|p| condition(p, end_line))
-        .ok_or_else(|| "no result".into())
+        .ok_or_else(|| crate::Error::no_solution("no result"))
 }
 
 /// Drops a single bit of sand until it settles, marks it on the map, and returns the
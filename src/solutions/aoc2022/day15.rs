@@ -38,7 +38,7 @@ pub fn two(input: &str) -> crate::Result<i64> {
         .iter()
         .flat_map(|&sensor| points_just_outside_range(sensor))
         .find(|&p| is_hidden_beacon(&sensors, p))
-        .ok_or("no result")?;
+        .ok_or_else(|| crate::Error::no_solution("no result"))?;
 
     Ok(x as i64 * LIMIT as i64 + y as i64)
 }
@@ -2,7 +2,8 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Find the optimal amount of pressure release.
 pub fn one(input: &str) -> crate::Result<i32> {
-    let (starting_position, valves) = parse(input).ok_or("parse failed")?;
+    let (starting_position, valves) =
+        parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     let distances = build_distance_matrix(&valves);
 
     Ok(find_optimal_pressure_release(
@@ -17,7 +18,8 @@ pub fn one(input: &str) -> crate::Result<i32> {
 ///
 /// Currently pretty slow (nearly 15 seconds).
 pub fn two(input: &str) -> crate::Result<i32> {
-    let (starting_position, valves) = parse(input).ok_or("parse failed")?;
+    let (starting_position, valves) =
+        parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     let distances = build_distance_matrix(&valves);
 
     Ok(find_optimal_pressure_release(
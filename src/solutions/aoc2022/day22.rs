@@ -2,14 +2,18 @@ use std::collections::HashMap;
 
 /// Find a checksum of the position after performing all instructions on a wrapping map.
 pub fn one(input: &str) -> crate::Result<i32> {
-    let (chunks, instructions) = parse(input).ok_or("parse failed")?;
-    Ok(execute_walk(&chunks, &instructions, construct_wrapping_links).ok_or("failed walk")?)
+    let (chunks, instructions) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
+    Ok(
+        execute_walk(&chunks, &instructions, construct_wrapping_links)
+            .ok_or_else(|| crate::Error::no_solution("failed walk"))?,
+    )
 }
 
 /// Find a checksum of the position after performing all instructions on a cube map.
 pub fn two(input: &str) -> crate::Result<i32> {
-    let (chunks, instructions) = parse(input).ok_or("parse failed")?;
-    Ok(execute_walk(&chunks, &instructions, construct_cube_links).ok_or("failed walk")?)
+    let (chunks, instructions) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
+    Ok(execute_walk(&chunks, &instructions, construct_cube_links)
+        .ok_or_else(|| crate::Error::no_solution("failed walk"))?)
 }
 
 /// Constructs the [`Link`]s for 2D wrapping.
@@ -1,14 +1,16 @@
 use std::collections::{HashSet, VecDeque};
 
+use crate::common::{Addressing, Grid};
+
 /// Find the length of the shortest path to the exit.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let map = parse(input).ok_or("parse failed")?;
+    let map = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     Ok(map.shortest_path(1))
 }
 
 /// Find the length of the shortest path to the exit, start and exit again.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let map = parse(input).ok_or("parse failed")?;
+    let map = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     Ok(map.shortest_path(3))
 }
 
@@ -82,22 +84,22 @@ fn parse(input: &str) -> Option<Map> {
         .filter(|line| !line.contains("###"))
         .map(|line| line.trim_matches('#'))
         .collect();
+    let grid = Grid::from_iters(map.iter().map(|line| line.bytes()));
     let cycles = map.len() * map[0].len();
 
+    // Blizzards wrap around to the other side of the valley when they reach a wall.
     let mut data = vec![HashSet::new(); cycles];
     for i in 0..cycles as i32 {
-        for y in 0..map.len() as i32 {
-            for x in 0..map[y as usize].len() as i32 {
-                let p = match map[y as usize].as_bytes()[x as usize] {
-                    b'<' => Some(((x - i).rem_euclid(map[y as usize].len() as i32), y)),
-                    b'^' => Some((x, (y - i).rem_euclid(map.len() as i32))),
-                    b'>' => Some(((x + i).rem_euclid(map[y as usize].len() as i32), y)),
-                    b'v' => Some((x, (y + i).rem_euclid(map.len() as i32))),
-                    _ => None,
-                };
+        for ((x, y), cell) in grid.iter_with_position() {
+            let p = match cell {
+                b'<' => (x - i, y),
+                b'^' => (x, y - i),
+                b'>' => (x + i, y),
+                b'v' => (x, y + i),
+                _ => continue,
+            };
 
-                data[i as usize].extend(p);
-            }
+            data[i as usize].extend(grid.resolve(p, Addressing::Wrap));
         }
     }
     Some(Map {
@@ -1,8 +1,10 @@
 use std::ops::Range;
 
+use crate::common::sections;
+
 /// Map all the input seeds to final locations, and find the lowest location.
 pub fn one(input: &str) -> crate::Result<i64> {
-    let (mut seeds, mappings) = parse(input).ok_or("parse failed")?;
+    let (mut seeds, mappings) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
 
     for mapping in mappings {
         for seed in &mut seeds {
@@ -21,7 +23,7 @@ pub fn one(input: &str) -> crate::Result<i64> {
 /// Like `one`, but treat the input seeds as ranges; every two numbers are a (start of range,
 /// length of range) pair.
 pub fn two(input: &str) -> crate::Result<i64> {
-    let (seeds, mappings) = parse(input).ok_or("parse failed")?;
+    let (seeds, mappings) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     let mut seed_ranges: Vec<_> = seeds.chunks(2).map(|c| (c[0], c[0] + c[1])).collect();
 
     for mapping in mappings {
@@ -69,7 +71,7 @@ type Seeds = Vec<i64>;
 
 /// Parses the puzzle input into a list of seeds and list mapping tables.
 fn parse(input: &str) -> Option<(Seeds, Vec<Mappings>)> {
-    let mut chunks = input.split("\r\n\r\n");
+    let mut chunks = sections(input);
     let (_, seeds) = chunks.next()?.split_once(": ")?;
 
     let seeds = seeds
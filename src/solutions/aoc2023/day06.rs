@@ -1,7 +1,13 @@
 /// Find the number of ways to beat each race in the input, and multiply them together.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let time = input.lines().next().ok_or("insufficient input")?;
-    let distance = input.lines().nth(1).ok_or("insufficient input")?;
+    let time = input
+        .lines()
+        .next()
+        .ok_or_else(|| crate::Error::invalid_input("insufficient input"))?;
+    let distance = input
+        .lines()
+        .nth(1)
+        .ok_or_else(|| crate::Error::invalid_input("insufficient input"))?;
 
     let time = time.split_whitespace().filter_map(|n| n.parse().ok());
     let distance = distance.split_whitespace().filter_map(|n| n.parse().ok());
@@ -14,8 +20,14 @@ pub fn one(input: &str) -> crate::Result<usize> {
 /// Find the number of ways to beat the race if we treat all digits on each input line as one big
 /// number.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let time = input.lines().next().ok_or("insufficient input")?;
-    let distance = input.lines().nth(1).ok_or("insufficient input")?;
+    let time = input
+        .lines()
+        .next()
+        .ok_or_else(|| crate::Error::invalid_input("insufficient input"))?;
+    let distance = input
+        .lines()
+        .nth(1)
+        .ok_or_else(|| crate::Error::invalid_input("insufficient input"))?;
 
     let race = (parse_as_single(time)?, parse_as_single(distance)?);
     Ok(ways_to_beat(race))
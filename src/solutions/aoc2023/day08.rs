@@ -1,15 +1,17 @@
 use std::collections::HashMap;
 
+use crate::common::split_section;
+
 /// Find the number of steps required to reach "ZZZ" from "AAA".
 pub fn one(input: &str) -> crate::Result<usize> {
-    let (steps, map) = parse(input).ok_or("failed parse")?;
+    let (steps, map) = parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
     Ok(steps_required(steps, &map, "AAA", "ZZZ"))
 }
 
 /// Find the number of steps required such that starting at all points ending with "A", you
 /// simultaneously arrive at points ending with "Z".
 pub fn two(input: &str) -> crate::Result<usize> {
-    let (steps, map) = parse(input).ok_or("failed parse")?;
+    let (steps, map) = parse(input).ok_or_else(|| crate::Error::parse("failed parse"))?;
 
     map.keys()
         .filter(|k| k.ends_with('A'))
@@ -35,7 +37,7 @@ type Map<'a> = HashMap<&'a str, [&'a str; 2]>;
 
 /// Parses the puzzle input into the step instructions and a map.
 pub fn parse(input: &str) -> Option<(&str, Map)> {
-    let (steps, map) = input.split_once("\r\n")?;
+    let (steps, map) = split_section(input)?;
     let map = map.lines().filter_map(|line| {
         let (key, rest) = line.split_once(" = (")?;
         let val: [&str; 2] = rest.trim_end_matches(')').split_once(", ")?.into();
@@ -1,6 +1,7 @@
 /// Find half the length of the loop.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let (map, start_position, dir) = parse(input).ok_or("parse failed")?;
+    let (map, start_position, dir) =
+        parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
 
     let (mut state, mut steps) = ((start_position, dir), 0);
     loop {
@@ -20,7 +21,8 @@ pub fn one(input: &str) -> crate::Result<usize> {
 /// [shoelace formula]: https://en.wikipedia.org/wiki/Shoelace_formula
 /// [Pick's theorem]: https://en.wikipedia.org/wiki/Pick%27s_theorem
 pub fn two(input: &str) -> crate::Result<i32> {
-    let (map, mut pos, mut dir) = parse(input).ok_or("parse failed")?;
+    let (map, mut pos, mut dir) =
+        parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
 
     // Compute all points on the shape.
     let mut shape = vec![pos];
@@ -1,3 +1,5 @@
+use crate::common::sections;
+
 /// Find the total reflection score.
 pub fn one(input: &str) -> crate::Result<usize> {
     Ok(total_reflection_score(input, false))
@@ -10,8 +12,7 @@ pub fn two(input: &str) -> crate::Result<usize> {
 
 /// Finds the total reflection score of all maps in an input string.
 fn total_reflection_score(input: &str, smudged: bool) -> usize {
-    input
-        .split("\r\n\r\n")
+    sections(input)
         .map(|chunk| {
             let map: Vec<_> = chunk.lines().map(|l| l.as_bytes()).collect();
             reflection_score(&map, smudged)
@@ -1,8 +1,10 @@
 use std::{cmp::Ordering, collections::HashMap};
 
+use crate::common::split_section;
+
 /// Calculate a checksum from all input [`Thing`]s that get accepted.
 pub fn one(input: &str) -> crate::Result<i64> {
-    let (flows, things) = parse(input).ok_or("parse failed")?;
+    let (flows, things) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
 
     Ok(things
         .filter_map(|thing| {
@@ -20,7 +22,7 @@ pub fn one(input: &str) -> crate::Result<i64> {
 
 /// Count the total number of possible [`Thing`]s that would be accepted.
 pub fn two(input: &str) -> crate::Result<i64> {
-    let (flows, _) = parse(input).ok_or("parse failed")?;
+    let (flows, _) = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     Ok(count_accepts(&flows, "in", [(1, 4000); 4]))
 }
 
@@ -96,7 +98,7 @@ type Thing = [i64; 4];
 
 /// Parses the puzzle input into a [flow name => flow branches] map and a list of [`Thing`]s.
 fn parse(input: &str) -> Option<(Flows, impl Iterator<Item = Thing> + '_)> {
-    let (flows, things) = input.split_once("\r\n\r\n")?;
+    let (flows, things) = split_section(input)?;
     let mut flow_map = HashMap::new();
 
     for flow in flows.lines() {
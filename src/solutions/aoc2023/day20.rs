@@ -2,7 +2,8 @@ use std::collections::{HashMap, VecDeque};
 
 /// Find the total number of sent `low` and `high` signals in 1000 button presses.
 pub fn one(input: &str) -> crate::Result<usize> {
-    let (mut modules, broadcaster_id) = parse(input).ok_or("parse failed")?;
+    let (mut modules, broadcaster_id) =
+        parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     let (mut lo, mut hi) = (0, 0);
     for _ in 0..1000 {
         let (added_lo, added_hi, _) = press_button(&mut modules, broadcaster_id);
@@ -13,7 +14,8 @@ pub fn one(input: &str) -> crate::Result<usize> {
 
 /// Find the number of steps required for `rx` to receive a `low` input.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let (mut modules, broadcaster_id) = parse(input).ok_or("parse failed")?;
+    let (mut modules, broadcaster_id) =
+        parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
 
     // The last module in the list is always `rx`. `rx` has one input, a conjunction; so for `rx`
     // to receive a low, all inputs to the conjunction must be high. Thus, we simply measure the
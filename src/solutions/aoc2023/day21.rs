@@ -1,5 +1,7 @@
 use std::collections::{HashSet, VecDeque};
 
+use crate::common::param;
+
 /// Find the number of tiles the elf could end on after 64 steps.
 ///
 /// Leaning on a special flood fill, see [`floodfill`].
@@ -10,7 +12,8 @@ pub fn one(input: &str) -> crate::Result<usize> {
         .find(|&(x, y)| map[y][x] == b'S')
         .ok_or("no start")?;
 
-    Ok(floodfill(&map, &[start], 64))
+    // The example uses fewer steps, which can be set through the `steps` parameter.
+    Ok(floodfill(&map, &[start], param("steps", 64)))
 }
 
 /// Given an infinitely-wrapping map and a lot of steps, find the number of tiles the elf could end
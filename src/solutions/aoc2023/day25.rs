@@ -11,7 +11,7 @@ pub fn one(input: &str) -> crate::Result<usize> {
     //
     // Once we reach 3 nodes in the border, we have our solution.
 
-    let g = parse(input).ok_or("no parse")?;
+    let g = parse(input).ok_or_else(|| crate::Error::parse("no parse"))?;
     let mut blob = HashSet::from([0]);
     let mut border: HashSet<usize> = HashSet::from_iter(g[0].iter().copied());
 
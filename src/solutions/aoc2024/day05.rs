@@ -3,9 +3,11 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
+use crate::common::split_section;
+
 /// Counting only correct updates, find the sum of their middle values.
 pub fn one(input: &str) -> crate::Result<i32> {
-    let (rules, updates) = parse(input).ok_or("no parse")?;
+    let (rules, updates) = parse(input).ok_or_else(|| crate::Error::parse("no parse"))?;
     Ok(updates
         .filter(|xs| {
             xs.iter()
@@ -18,7 +20,7 @@ pub fn one(input: &str) -> crate::Result<i32> {
 
 /// Counting only incorrect updates, fix them and find the sum of their middle values.
 pub fn two(input: &str) -> crate::Result<i32> {
-    let (rules, updates) = parse(input).ok_or("no parse")?;
+    let (rules, updates) = parse(input).ok_or_else(|| crate::Error::parse("no parse"))?;
     Ok(updates
         .filter_map(|mut xs| {
             let prev = xs.clone();
@@ -41,7 +43,7 @@ type RuleMap = HashMap<i32, HashSet<i32>>;
 
 /// Parses the puzzle input into a map of rules and a list of updates to check.
 fn parse(input: &str) -> Option<(RuleMap, impl Iterator<Item = Vec<i32>> + '_)> {
-    let (rules, updates) = input.split_once("\r\n\r\n")?;
+    let (rules, updates) = split_section(input)?;
     let mut rule_map = RuleMap::new();
 
     for line in rules.lines() {
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::common::Grid;
+use crate::common::{Grid, CARDINAL};
 
 /// For each trailhead, find the number of reachable peaks; sum all those numbers.
 pub fn one(input: &str) -> crate::Result<usize> {
@@ -33,14 +33,11 @@ fn score_and_rating(grid: &Grid<u8>, (x, y): (i32, i32)) -> (usize, usize) {
     let mut stack = vec![(x, y, b'0')];
     let mut goals = HashMap::new();
     while let Some((x, y, c)) = stack.pop() {
-        for (dx, dy) in [(-1, 0), (0, -1), (1, 0), (0, 1)] {
-            let (x, y) = (x + dx, y + dy);
-            if let Some(n) = grid.get((x, y)) {
-                match (c, n) {
-                    (b'8', b'9') => *goals.entry((x, y)).or_default() += 1,
-                    _ if *n == (c + 1) => stack.push((x, y, *n)),
-                    _ => {}
-                }
+        for (x, y) in grid.neighbours((x, y), &CARDINAL) {
+            match (c, grid[(x, y)]) {
+                (b'8', b'9') => *goals.entry((x, y)).or_default() += 1,
+                (_, n) if n == c + 1 => stack.push((x, y, n)),
+                _ => {}
             }
         }
     }
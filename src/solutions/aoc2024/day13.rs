@@ -1,3 +1,5 @@
+use crate::common::sections;
+
 /// Find the total cost required to win all winnable machines.
 pub fn one(input: &str) -> crate::Result<i128> {
     Ok(parse(input).filter_map(win_cost).sum())
@@ -39,7 +41,7 @@ fn win_cost((x1, y1, x2, y2, x, y): Machine) -> Option<i128> {
 
 /// Parses the puzzle input into a list of machine parameters.
 fn parse(input: &str) -> impl Iterator<Item = Machine> + '_ {
-    input.split("\r\n\r\n").filter_map(|chunk| {
+    sections(input).filter_map(|chunk| {
         let mut lines = chunk.lines().filter_map(|line| {
             let (_, v) = line.split_once(": ")?;
             let (x, y) = v.split_once(", ")?;
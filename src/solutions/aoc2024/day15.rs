@@ -1,6 +1,6 @@
 use std::mem;
 
-use crate::common::Grid;
+use crate::common::{split_section, Grid};
 
 /// Find the box checksum after performing all steps.
 pub fn one(input: &str) -> crate::Result<i32> {
@@ -127,7 +127,7 @@ type V2 = (i32, i32);
 /// instructions for the robot to follow. If `wide` is given, interprets the grid as twice as wide,
 /// with the expansion rules described in the puzzle.
 fn parse(input: &str, wide: bool) -> (V2, Grid<'_, Cell>, impl Iterator<Item = u8> + '_) {
-    let (map, instructions) = input.split_once("\r\n\r\n").expect("correct input");
+    let (map, instructions) = split_section(input).expect("correct input");
     let robot =
         Grid::from_input(map)
             .find(|&e| e == b'@')
@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 /// Run the program, get the output.
 pub fn one(input: &str) -> crate::Result<String> {
-    let mut m = parse(input).ok_or("parse failed")?;
+    let mut m = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
     m.run();
 
     Ok(m.output
@@ -32,7 +32,7 @@ pub fn one(input: &str) -> crate::Result<String> {
 /// so, it recursively tries all 8 possible next octets. Repeat until the desired output length is
 /// reached, at which point we can check for equality to find our final answer.
 pub fn two(input: &str) -> crate::Result<i64> {
-    let m = parse(input).ok_or("parse failed")?;
+    let m = parse(input).ok_or_else(|| crate::Error::parse("parse failed"))?;
 
     (1..8)
         .filter_map(|a| find_lowest_code(&m, a))
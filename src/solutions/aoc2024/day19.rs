@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::common::split_section;
+
 /// Find the number of desired arrangements that can actually be formed.
 pub fn one(input: &str) -> crate::Result<usize> {
     Ok(solve(input).0)
@@ -45,6 +47,6 @@ fn count_arrangements<'a>(towels: &[&str], pattern: &'a str, cache: &mut Cache<'
 
 /// Parses the puzzle input into a list of towels (prefixes) and sequence of arrangements.
 fn parse(input: &str) -> (Vec<&str>, impl Iterator<Item = &str> + '_) {
-    let (towels, patterns) = input.split_once("\r\n\r\n").expect("two sections in input");
+    let (towels, patterns) = split_section(input).expect("two sections in input");
     (towels.split(", ").collect(), patterns.lines())
 }
@@ -63,7 +63,7 @@ pub fn two(input: &str) -> crate::Result<String> {
     let clique = cliques
         .into_iter()
         .max_by_key(|c| c.len())
-        .ok_or("no result")?;
+        .ok_or_else(|| crate::Error::no_solution("no result"))?;
 
     let mut clique: Vec<_> = clique.into_iter().collect();
     clique.sort();
@@ -10,7 +10,7 @@ pub fn one(input: &str) -> crate::Result<usize> {
 
 /// Count how many rolls of paper can be removed if accessible rolls get removed repeatedly.
 pub fn two(input: &str) -> crate::Result<usize> {
-    let mut g = Grid::from_input(input);
+    let mut g: Grid<u8> = input.parse()?;
     let mut changed = true;
     let mut removed = 0;
 
@@ -1,3 +1,5 @@
+use crate::common::split_section;
+
 /// Count the number of fresh ingredients by comparing their IDs to the fresh ingredient ranges.
 pub fn one(input: &str) -> crate::Result<usize> {
     let (ranges, numbers) = parse(input);
@@ -41,7 +43,7 @@ fn overlap((alo, ahi): (u64, u64), (blo, bhi): (u64, u64)) -> Option<(u64, u64)>
 /// Parses the puzzle input into a list of fresh ingredient ID ranges, and a list of actual
 /// ingredient IDs.
 fn parse(input: &str) -> (Vec<(u64, u64)>, impl Iterator<Item = u64> + '_) {
-    let (ranges, numbers) = input.split_once("\r\n\r\n").unwrap();
+    let (ranges, numbers) = split_section(input).unwrap();
 
     (
         ranges
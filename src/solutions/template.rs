@@ -1,7 +1,7 @@
 pub fn one(input: &str) -> crate::Result<&str> {
-    Err("unimplemented".into())
+    Err(crate::Error::Unimplemented)
 }
 
 pub fn two(input: &str) -> crate::Result<&str> {
-    Err("unimplemented".into())
+    Err(crate::Error::Unimplemented)
 }